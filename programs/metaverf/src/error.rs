@@ -13,4 +13,190 @@ pub enum CertificateError {
     
     #[msg("Collection limit reached")]
     CollectionLimitReached,
+
+    #[msg("Signer is not the protocol admin")]
+    UnauthorizedAdmin,
+
+    #[msg("No admin transfer is pending")]
+    NoPendingAdmin,
+
+    #[msg("Signer is not the pending admin")]
+    UnauthorizedPendingAdmin,
+
+    #[msg("Council mode is active, use a council proposal")]
+    CouncilModeActive,
+
+    #[msg("Council mode is not active")]
+    CouncilModeInactive,
+
+    #[msg("Signer is not a council member")]
+    NotCouncilMember,
+
+    #[msg("Invalid council members or threshold")]
+    InvalidCouncil,
+
+    #[msg("Proposal already approved by this member")]
+    ProposalAlreadyApproved,
+
+    #[msg("Proposal does not have enough approvals")]
+    ProposalThresholdNotMet,
+
+    #[msg("Proposal action does not match this instruction")]
+    InvalidProposalAction,
+
+    #[msg("Withdraw destination does not match the proposal")]
+    InvalidWithdrawDestination,
+
+    #[msg("Parameter delay cannot be negative")]
+    InvalidParameterDelay,
+
+    #[msg("No parameter change is pending")]
+    NoPendingParameters,
+
+    #[msg("Pending parameters are not effective yet")]
+    ParametersNotYetEffective,
+
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+
+    #[msg("Mint is not the protocol payment mint")]
+    InvalidPaymentMint,
+
+    #[msg("Accepted mint does not match the proposal")]
+    InvalidAcceptedMint,
+
+    #[msg("Collection name or uri is too long")]
+    CollectionMetadataTooLong,
+
+    #[msg("Account is already on the current version")]
+    AlreadyMigrated,
+
+    #[msg("Signer is not the program upgrade authority")]
+    NotUpgradeAuthority,
+
+    #[msg("Signer is neither the admin nor holds the required role")]
+    MissingRole,
+
+    #[msg("Payout recipients must be unique and their shares must add up to 10000 bps")]
+    InvalidPayoutConfig,
+
+    #[msg("No payout recipients are configured")]
+    PayoutNotConfigured,
+
+    #[msg("Payout token account does not match the configured recipient")]
+    InvalidPayoutAccount,
+
+    #[msg("Grace period cannot be negative")]
+    InvalidGracePeriod,
+
+    #[msg("College subscription has not lapsed")]
+    SubscriptionNotLapsed,
+
+    #[msg("Prepay discounts need a non-zero period count and less than 10000 bps")]
+    InvalidPrepayDiscounts,
+
+    #[msg("Renewal period count is out of range")]
+    InvalidRenewalPeriods,
+
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+
+    #[msg("Plan is invalid, inactive or not the college's plan")]
+    InvalidPlan,
+
+    #[msg("College has more collections than the plan allows")]
+    PlanLimitExceeded,
+
+    #[msg("Certificate quota for this period is used up")]
+    CertificateQuotaExceeded,
+
+    #[msg("Payment accounts are required while a certificate fee is set")]
+    MissingFeeAccounts,
+
+    #[msg("Refund share must be at most 10_000 bps")]
+    InvalidRefundPolicy,
+
+    #[msg("Subscription is not due for renewal yet")]
+    RenewalNotDue,
+
+    #[msg("Fee vault does not hold enough for a renewal")]
+    InsufficientVaultBalance,
+
+    #[msg("Fee vault must be closed first")]
+    FeeVaultOpen,
+
+    #[msg("Coupon is invalid, inactive or not valid for this college")]
+    InvalidCoupon,
+
+    #[msg("Coupon has expired")]
+    CouponExpired,
+
+    #[msg("Coupon has reached its usage cap")]
+    CouponExhausted,
+
+    #[msg("SOL payments are off or the price account is invalid")]
+    InvalidPriceFeed,
+
+    #[msg("Oracle price is too old")]
+    StalePrice,
+
+    #[msg("Oracle price confidence interval is too wide")]
+    PriceTooUncertain,
+
+    #[msg("College profile field is empty, too long or malformed")]
+    InvalidCollegeProfile,
+
+    #[msg("No college authority transfer is pending")]
+    NoPendingCollegeAuthority,
+
+    #[msg("Signer is not the pending college authority")]
+    UnauthorizedPendingCollegeAuthority,
+
+    #[msg("Issuer delegate needs one to ten collections and an expiry in the future")]
+    InvalidIssuerDelegate,
+
+    #[msg("Issuer delegate has expired")]
+    IssuerDelegateExpired,
+
+    #[msg("Issuer delegate may not mint into this collection")]
+    CollectionNotDelegated,
+
+    #[msg("Issuer delegate has reached its daily cap")]
+    DailyCapReached,
+
+    #[msg("Accreditor name is empty or too long")]
+    InvalidAccreditor,
+
+    #[msg("Accreditor is not active")]
+    AccreditorNotActive,
+
+    #[msg("Programme is empty or longer than 32 bytes")]
+    InvalidProgramme,
+
+    #[msg("Accreditation expiry must be 0 or in the future")]
+    InvalidAccreditationExpiry,
+
+    #[msg("Accreditation is not active")]
+    AccreditationNotActive,
+
+    #[msg("Accreditation is still valid, it has not expired and its accreditor is active")]
+    AccreditationStillValid,
+
+    #[msg("Subscription duration must be positive")]
+    InvalidSubscriptionDuration,
+
+    #[msg("Subscription has lapsed past its grace period, renew it first")]
+    SubscriptionExpired,
+
+    #[msg("The SOL vault is required to refund a college that paid in SOL")]
+    MissingSolVault,
+
+    #[msg("Issuer delegate was granted by a previous college authority")]
+    StaleIssuerDelegate,
+
+    #[msg("College already holds unused payments in too many mints, pay in one of them")]
+    TooManyPaymentMints,
+
+    #[msg("Each token payment needs its mint, treasury and college token account, in order")]
+    InvalidRefundAccounts,
 }
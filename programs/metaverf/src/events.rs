@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::{AccreditationStatus, DeactivationReason, Payment};
+
+#[event]
+pub struct CollegeDeactivated {
+    pub college_id: u64,
+    pub reason: DeactivationReason,
+    pub deactivated_at: i64,
+}
+
+#[event]
+pub struct SubscriptionAutoRenewed {
+    pub college_id: u64,
+    pub amount: u64,//Paid from the college's fee vault
+    pub paid_until: i64,
+}
+
+#[event]
+pub struct CollegeClosed {
+    pub college_id: u64,
+    pub refunds: Vec<Payment>,//Paid out in the mint each payment was made in
+    pub closed_at: i64,
+}
+
+#[event]
+pub struct AccreditationChanged {
+    pub college_id: u64,
+    pub accreditor: Pubkey,
+    pub programme: String,
+    pub status: AccreditationStatus,
+    pub expires_at: i64,
+}
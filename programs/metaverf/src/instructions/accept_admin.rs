@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::MetaverfAccount;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = metaverf_account.pending_admin.is_some() @ CertificateError::NoPendingAdmin,
+        constraint = metaverf_account.pending_admin == Some(new_admin.key()) @ CertificateError::UnauthorizedPendingAdmin,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn accept_admin(&mut self) -> Result<()> {
+        self.metaverf_account.admin = self.new_admin.key();
+        self.metaverf_account.pending_admin = None;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{CollegeAccount, CollegeLookup};
+use crate::error::CertificateError;
+use crate::utils::close_program_account;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct AcceptCollegeAuthority<'info> {
+    #[account(mut)]
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.pending_authority.is_some() @ CertificateError::NoPendingCollegeAuthority,
+        constraint = college_account.pending_authority == Some(new_authority.key()) @ CertificateError::UnauthorizedPendingCollegeAuthority,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        mut,
+        address = college_account.authority @ CertificateError::NotAuthorized,
+    )]
+    pub old_authority: SystemAccount<'info>,//Gets the rent of its lookup back
+
+    #[account(
+        mut,
+        seeds = [b"college_lookup", old_authority.key().as_ref()],
+        bump
+    )]
+    ///CHECK: Always the old key's lookup address, it may be empty for colleges from before lookups
+    pub old_lookup: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = new_authority,
+        seeds = [b"college_lookup", new_authority.key().as_ref()],
+        bump,
+        space = 8 + CollegeLookup::INIT_SPACE
+    )]
+    pub new_lookup: Account<'info, CollegeLookup>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AcceptCollegeAuthority<'info> {
+    pub fn accept_college_authority(&mut self, bumps: &AcceptCollegeAuthorityBumps) -> Result<()> {
+        let old_authority = self.college_account.authority;
+
+        // The old key's lookup must not keep pointing here once it has handed the college over
+        if self.old_lookup.owner == &crate::ID {
+            let lookup = CollegeLookup::try_deserialize(&mut &self.old_lookup.try_borrow_data()?[..])?;
+            if lookup.college == self.college_account.key() {
+                close_program_account(&self.old_lookup.to_account_info(), &self.old_authority.to_account_info())?;
+            }
+        }
+
+        // An update authority that was never split off follows the rotation, otherwise it stays
+        if self.college_account.update_authority == old_authority {
+            self.college_account.update_authority = self.new_authority.key();
+        }
+        self.college_account.authority = self.new_authority.key();
+        self.college_account.pending_authority = None;
+
+        self.new_lookup.set_inner(CollegeLookup {
+            college_id: self.college_account.college_id,
+            college: self.college_account.key(),
+            bump: bumps.new_lookup,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::state::{AcceptedMint, MetaverfAccount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct AddAcceptedMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = mint.key() != metaverf_account.payment_mint @ CertificateError::InvalidPaymentMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"accepted_mint", mint.key().as_ref()],
+        bump,
+        space = 8 + AcceptedMint::INIT_SPACE,
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = metaverf_account
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,//The treasury for fees paid in this mint
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddAcceptedMint<'info> {
+    pub fn add_accepted_mint(&mut self, annual_fee: u64, bumps: &AddAcceptedMintBumps) -> Result<()> {
+        // In council mode the admin can list a mint, but only a council proposal can enable it
+        self.accepted_mint.set_inner(AcceptedMint {
+            mint: self.mint.key(),
+            annual_fee,
+            pending_annual_fee: None,
+            fee_effective_at: 0,
+            enabled: !self.metaverf_account.council_enabled(),
+            bump: bumps.accepted_mint,
+        });
+
+        Ok(())
+    }
+}
@@ -5,24 +5,44 @@ use mpl_core::{
     ID as MPL_CORE_ID,
 };
 
-use crate::state::CollegeAccount;
+use crate::state::{CollegeAccount, CollegeProfile, MetaverfAccount, Plan};
 use crate::error::CertificateError;
 use crate::college::CollectionInfo;
 
 #[derive(Accounts)]
-#[instruction(college_id: u16)]
+#[instruction(college_id: u64)]
 pub struct AddCollection<'info> {
     #[account(mut)]
     pub college_authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"college", college_account.id.to_le_bytes().as_ref()],
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
         bump = college_account.bump,
         constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
         constraint = college_account.active @ CertificateError::CollegeNotActive,
     )]
     pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        seeds = [b"college_profile", college_account.key().as_ref()],
+        bump = college_profile.bump
+    )]
+    pub college_profile: Account<'info, CollegeProfile>,//Its name is stamped on the collection
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = !metaverf_account.paused @ CertificateError::ProtocolPaused,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        seeds = [b"plan", plan.id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        constraint = college_account.plan == Some(plan.key()) @ CertificateError::InvalidPlan,
+    )]
+    pub plan: Option<Account<'info, Plan>>,//Must be passed when the college is on a plan
     
     #[account(mut)]
     ///CHECK: UncheckedAccount will be checked by mpl
@@ -42,17 +62,30 @@ pub struct AddCollectionArgs {
 
 impl<'info> AddCollection<'info> {
     pub fn add_collection(&mut self, args: AddCollectionArgs) -> Result<()> {
-        // Check if collection limit is reached
+        // Deactivating a lapsed college is left to `expire_college`
+        let now = Clock::get()?.unix_timestamp;
+        if self.college_account.is_lapsed(&self.metaverf_account, now) {
+            return err!(CertificateError::SubscriptionExpired);
+        }
+
+        self.college_account.require_plan(self.plan.as_deref())?;
+
+        // Check if the plan's collection limit is reached
         require!(
-            self.college_account.collections.len() < 10,
+            self.college_account.collections.len() < CollegeAccount::collection_limit(self.plan.as_deref()),
             CertificateError::CollectionLimitReached
         );
+        require!(
+            args.name.len() <= 64 && args.uri.len() <= 128,
+            CertificateError::CollectionMetadataTooLong
+        );
 
-        // Create the new collection with college as owner and update authority
+        // The college PDA is the update authority, so metadata control follows `update_authority`
+        // and minting survives an authority rotation
         CreateCollectionV2CpiBuilder::new(&self.mpl_core_program.to_account_info())
             .collection(&self.new_collection.to_account_info())
             .payer(&self.college_authority.to_account_info())
-            .update_authority(Some(&self.college_authority.to_account_info()))
+            .update_authority(Some(&self.college_account.to_account_info()))
             .system_program(&self.system_program.to_account_info())
             .name(args.name.clone())
             .uri(args.uri.clone())
@@ -62,7 +95,11 @@ impl<'info> AddCollection<'info> {
                         attribute_list: vec![
                             Attribute {
                                 key: "College ID".to_string(),
-                                value: self.college_account.id.to_string(),
+                                value: self.college_account.college_id.to_string(),
+                            },
+                            Attribute {
+                                key: "College Name".to_string(),
+                                value: self.college_profile.name.clone(),
                             },
                             Attribute {
                                 key: "Collection Type".to_string(),
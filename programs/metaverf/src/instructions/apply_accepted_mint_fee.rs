@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::AcceptedMint;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct ApplyAcceptedMintFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"accepted_mint", accepted_mint.mint.as_ref()],
+        bump = accepted_mint.bump,
+        constraint = accepted_mint.pending_annual_fee.is_some() @ CertificateError::NoPendingParameters,
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+}
+
+impl<'info> ApplyAcceptedMintFee<'info> {
+    // Permissionless like `apply_parameters`
+    pub fn apply_accepted_mint_fee(&mut self) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp >= self.accepted_mint.fee_effective_at,
+            CertificateError::ParametersNotYetEffective
+        );
+
+        if let Some(fee) = self.accepted_mint.pending_annual_fee.take() {
+            self.accepted_mint.annual_fee = fee;
+        }
+
+        Ok(())
+    }
+}
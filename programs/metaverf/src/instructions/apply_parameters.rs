@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{MetaverfAccount, PendingParameters};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct ApplyParameters<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_parameters"],
+        bump = pending_parameters.bump,
+        constraint = pending_parameters.is_pending() @ CertificateError::NoPendingParameters,
+    )]
+    pub pending_parameters: Account<'info, PendingParameters>,
+}
+
+impl<'info> ApplyParameters<'info> {
+    // Permissionless, anyone can activate a queued change once its notice window has passed
+    pub fn apply_parameters(&mut self) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp >= self.pending_parameters.effective_at,
+            CertificateError::ParametersNotYetEffective
+        );
+
+        self.metaverf_account.update_parameters(
+            self.pending_parameters.annual_fee,
+            self.pending_parameters.subscription_duration,
+            self.pending_parameters.grace_period,
+            self.pending_parameters.certificate_fee,
+        );
+        self.pending_parameters.clear();
+
+        Ok(())
+    }
+}
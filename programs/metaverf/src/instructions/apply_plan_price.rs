@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::Plan;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct ApplyPlanPrice<'info> {
+    #[account(
+        mut,
+        seeds = [b"plan", plan.id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        constraint = plan.pending_price.is_some() @ CertificateError::NoPendingParameters,
+    )]
+    pub plan: Account<'info, Plan>,
+}
+
+impl<'info> ApplyPlanPrice<'info> {
+    // Permissionless like `apply_parameters`
+    pub fn apply_plan_price(&mut self) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp >= self.plan.price_effective_at,
+            CertificateError::ParametersNotYetEffective
+        );
+
+        if let Some(price) = self.plan.pending_price.take() {
+            self.plan.price = price;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::{CouncilProposal, MetaverfAccount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = metaverf_account.is_council_member(&member.key()) @ CertificateError::NotCouncilMember,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.approvals.contains(&member.key()) @ CertificateError::ProposalAlreadyApproved,
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+}
+
+impl<'info> ApproveProposal<'info> {
+    pub fn approve_proposal(&mut self) -> Result<()> {
+        // Keys that left the council still hold a slot, so drop them before the list can fill up
+        let council = &self.metaverf_account.council;
+        self.proposal.approvals.retain(|key| council.contains(key));
+        self.proposal.approvals.push(self.member.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::{Accreditation, AccreditationStatus, Accreditor, CollegeAccount};
+use crate::error::CertificateError;
+use crate::events::AccreditationChanged;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64, programme: String)]
+pub struct AttestAccreditation<'info> {
+    #[account(mut)]
+    pub accreditor_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"accreditor", accreditor_authority.key().as_ref()],
+        bump = accreditor.bump,
+        constraint = accreditor.active @ CertificateError::AccreditorNotActive,
+    )]
+    pub accreditor: Account<'info, Accreditor>,
+
+    #[account(
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    // Re-attesting a revoked or expired programme reuses its account
+    #[account(
+        init_if_needed,
+        payer = accreditor_authority,
+        seeds = [b"accreditation", accreditor.key().as_ref(), college_account.key().as_ref(), programme.as_bytes()],
+        bump,
+        space = 8 + Accreditation::INIT_SPACE
+    )]
+    pub accreditation: Account<'info, Accreditation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AttestAccreditation<'info> {
+    pub fn attest_accreditation(&mut self, programme: String, expires_at: i64, bumps: &AttestAccreditationBumps) -> Result<()> {
+        Accreditation::validate_programme(&programme)?;
+        let now = Clock::get()?.unix_timestamp;
+        require!(expires_at == 0 || expires_at > now, CertificateError::InvalidAccreditationExpiry);
+
+        self.accreditation.set_inner(Accreditation {
+            accreditor: self.accreditor.key(),
+            college: self.college_account.key(),
+            programme,
+            status: AccreditationStatus::Accredited,
+            attested_at: now,
+            expires_at,
+            revoked_at: 0,
+            bump: bumps.accreditation,
+        });
+
+        emit!(AccreditationChanged {
+            college_id: self.college_account.college_id,
+            accreditor: self.accreditor.key(),
+            programme: self.accreditation.programme.clone(),
+            status: AccreditationStatus::Accredited,
+            expires_at,
+        });
+
+        Ok(())
+    }
+}
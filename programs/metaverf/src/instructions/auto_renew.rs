@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+    token::{transfer_checked, TransferChecked},
+};
+
+use crate::state::{AcceptedMint, CollegeAccount, DeactivationReason, MetaverfAccount, Plan};
+use crate::events::SubscriptionAutoRenewed;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct AutoRenew<'info> {
+    #[account(
+        mut,
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.fee_vault_mint == Some(mint_usdc.key()) @ CertificateError::InvalidPaymentMint,
+        constraint = college_account.deactivation_reason != DeactivationReason::Suspended @ CertificateError::CollegeNotActive,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = !metaverf_account.paused @ CertificateError::ProtocolPaused,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        seeds = [b"plan", plan.id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        constraint = college_account.plan == Some(plan.key()) @ CertificateError::InvalidPlan,
+    )]
+    pub plan: Option<Account<'info, Plan>>,//Must be passed when the college is on a plan
+
+    pub mint_usdc: InterfaceAccount<'info, Mint>,//The mint the college's vault holds
+
+    #[account(
+        seeds = [b"accepted_mint", mint_usdc.key().as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,//Only needed when the vault holds an accepted mint
+
+    #[account(
+        mut,
+        seeds = [b"fee_vault", college_account.key().as_ref()],
+        bump,
+        token::mint = mint_usdc,
+        token::authority = college_account,
+        token::token_program = token_program,
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_usdc,
+        associated_token::authority = metaverf_account
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> AutoRenew<'info> {
+    // Permissionless, a keeper renews one period from the college's vault once the subscription is due
+    pub fn auto_renew(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= self.college_account.expires_at(&self.metaverf_account),
+            CertificateError::RenewalNotDue
+        );
+        self.college_account.require_plan(self.plan.as_deref())?;
+
+        let annual_fee = self.metaverf_account.annual_fee_in(
+            &self.mint_usdc.key(),
+            self.accepted_mint.as_deref(),
+            self.plan.as_deref(),
+        )?;
+        let amount = self.metaverf_account.renewal_cost(annual_fee, 1)?;
+        require!(self.fee_vault.amount >= amount, CertificateError::InsufficientVaultBalance);
+
+        let id = self.college_account.college_id.to_le_bytes();
+        let seeds = &[b"college".as_ref(), id.as_ref(), &[self.college_account.bump]];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = TransferChecked {
+            from: self.fee_vault.to_account_info(),
+            mint: self.mint_usdc.to_account_info(),
+            to: self.treasury.to_account_info(),
+            authority: self.college_account.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer);
+        transfer_checked(cpi_ctx, amount, self.mint_usdc.decimals)?;
+
+        self.college_account
+            .record_payment(self.mint_usdc.key(), amount, now)?;
+        self.college_account
+            .extend_subscription(&self.metaverf_account, 1, now)?;
+        self.college_account.activate();
+
+        emit!(SubscriptionAutoRenewed {
+            college_id: self.college_account.college_id,
+            amount,
+            paid_until: self.college_account.paid_until,
+        });
+
+        Ok(())
+    }
+}
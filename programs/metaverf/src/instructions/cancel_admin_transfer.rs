@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::state::MetaverfAccount;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct CancelAdminTransfer<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+        constraint = metaverf_account.pending_admin.is_some() @ CertificateError::NoPendingAdmin,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+}
+
+impl<'info> CancelAdminTransfer<'info> {
+    pub fn cancel_admin_transfer(&mut self) -> Result<()> {
+        self.metaverf_account.pending_admin = None;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::CollegeAccount;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct CancelCollegeAuthorityTransfer<'info> {
+    pub college_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
+        constraint = college_account.pending_authority.is_some() @ CertificateError::NoPendingCollegeAuthority,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+}
+
+impl<'info> CancelCollegeAuthorityTransfer<'info> {
+    pub fn cancel_college_authority_transfer(&mut self) -> Result<()> {
+        self.college_account.pending_authority = None;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+    token::{transfer_checked, TransferChecked},
+};
+
+use crate::state::{AcceptedMint, CollegeAccount, MetaverfAccount, Plan};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct ChangePlan<'info> {
+    #[account(mut)]
+    pub college_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
+        constraint = college_account.active @ CertificateError::CollegeNotActive,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = !metaverf_account.paused @ CertificateError::ProtocolPaused,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        seeds = [b"plan", current_plan.id.to_le_bytes().as_ref()],
+        bump = current_plan.bump,
+        constraint = college_account.plan == Some(current_plan.key()) @ CertificateError::InvalidPlan,
+    )]
+    pub current_plan: Option<Account<'info, Plan>>,//Must be passed when the college is on a plan
+
+    #[account(
+        seeds = [b"plan", new_plan.id.to_le_bytes().as_ref()],
+        bump = new_plan.bump,
+        constraint = new_plan.active @ CertificateError::InvalidPlan,
+    )]
+    pub new_plan: Option<Account<'info, Plan>>,//`None` moves the college back to the default annual fee
+
+    pub mint_usdc: InterfaceAccount<'info, Mint>,//The payment mint or one of the accepted mints
+
+    #[account(
+        seeds = [b"accepted_mint", mint_usdc.key().as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,//Only needed when paying in an accepted mint
+
+    #[account(
+        mut,
+        associated_token::mint = mint_usdc,
+        associated_token::authority = metaverf_account
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_usdc,
+        associated_token::authority = college_authority
+    )]
+    pub payer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ChangePlan<'info> {
+    // Upgrades pay the price difference for the time left, downgrades stretch the time left instead
+    pub fn change_plan(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        self.college_account.require_plan(self.current_plan.as_deref())?;
+
+        let new_plan_key = self.new_plan.as_ref().map(|plan| plan.key());
+        require!(self.college_account.plan != new_plan_key, CertificateError::InvalidPlan);
+        require!(
+            self.college_account.collections.len() <= CollegeAccount::collection_limit(self.new_plan.as_deref()),
+            CertificateError::PlanLimitExceeded
+        );
+
+        let mint = self.mint_usdc.key();
+        let accepted_mint = self.accepted_mint.as_deref();
+        let old_fee = self
+            .metaverf_account
+            .annual_fee_in(&mint, accepted_mint, self.current_plan.as_deref())?;
+        let new_fee = self
+            .metaverf_account
+            .annual_fee_in(&mint, accepted_mint, self.new_plan.as_deref())?;
+
+        let expires_at = self.college_account.expires_at(&self.metaverf_account);
+        let remaining = expires_at.saturating_sub(now).max(0) as u128;
+
+        if new_fee > old_fee {
+            let duration = self.metaverf_account.subscription_duration;
+            require!(duration > 0, CertificateError::InvalidSubscriptionDuration);
+            let amount = (new_fee - old_fee) as u128 * remaining / duration as u128;
+            let amount = u64::try_from(amount).map_err(|_| error!(CertificateError::MathOverflow))?;
+
+            if amount > 0 {
+                let cpi_accounts = TransferChecked {
+                    from: self.payer_token_account.to_account_info(),
+                    mint: self.mint_usdc.to_account_info(),
+                    to: self.treasury.to_account_info(),
+                    authority: self.college_authority.to_account_info(),
+                };
+
+                let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+                transfer_checked(cpi_ctx, amount, self.mint_usdc.decimals)?;
+                self.college_account.record_payment(mint, amount, now)?;
+            }
+        } else if new_fee < old_fee && new_fee > 0 {
+            // What is left of the payments now covers the stretched time
+            self.college_account.carry_payments(now);
+            let stretched = remaining * old_fee as u128 / new_fee as u128;
+            let stretched = i64::try_from(stretched).map_err(|_| error!(CertificateError::MathOverflow))?;
+            self.college_account.paid_until = now
+                .checked_add(stretched)
+                .ok_or(CertificateError::MathOverflow)?;
+        }
+
+        self.college_account.plan = new_plan_key;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+    token::{transfer_checked, TransferChecked},
+};
+
+use crate::state::{CollegeAccount, CollegeLookup, CollegeProfile, CollegeTombstone, MetaverfAccount, Payment, Plan};
+use crate::events::CollegeClosed;
+use crate::error::CertificateError;
+use crate::utils::transfer_from_sol_vault;
+
+#[derive(Accounts)]
+pub struct CloseCollege<'info> {
+    #[account(mut)]
+    pub college_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = college_authority,
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
+        constraint = college_account.fee_vault_mint.is_none() @ CertificateError::FeeVaultOpen,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        mut,
+        close = college_authority,
+        seeds = [b"college_lookup", college_authority.key().as_ref()],
+        bump = college_lookup.bump
+    )]
+    pub college_lookup: Account<'info, CollegeLookup>,
+
+    #[account(
+        mut,
+        close = college_authority,
+        seeds = [b"college_profile", college_account.key().as_ref()],
+        bump = college_profile.bump
+    )]
+    pub college_profile: Account<'info, CollegeProfile>,
+
+    #[account(
+        init,
+        payer = college_authority,
+        seeds = [b"tombstone", college_account.college_id.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + CollegeTombstone::INIT_SPACE,
+    )]
+    pub tombstone: Account<'info, CollegeTombstone>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = !metaverf_account.paused @ CertificateError::ProtocolPaused,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        seeds = [b"plan", plan.id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        constraint = college_account.plan == Some(plan.key()) @ CertificateError::InvalidPlan,
+    )]
+    pub plan: Option<Account<'info, Plan>>,//Must be passed when the college is on a plan
+
+    #[account(
+        mut,
+        seeds = [b"sol_vault"],
+        bump,
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,//Only needed to refund a payment in SOL
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,//Only needed to refund payments in tokens
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseCollege<'info> {
+    // `refund_accounts` holds the mint, the treasury and the college authority's token account of
+    // each payment in tokens, in the order of `payments`
+    pub fn close_college(&mut self, refund_accounts: &'info [AccountInfo<'info>], bumps: &CloseCollegeBumps) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        self.college_account.require_plan(self.plan.as_deref())?;
+
+        // Refunds are priced from what the college paid, suspended colleges leave without one. Time a
+        // sponsor paid for is refunded to the college authority too: sponsors pay on the college's
+        // behalf and are not tracked, so a sponsor wanting its money back has to settle with the college.
+        let unused = if self.college_account.active {
+            self.college_account.unused_payments(now)
+        } else {
+            Vec::new()
+        };
+
+        let mut refunds = Vec::with_capacity(unused.len());
+        let mut refund_accounts = refund_accounts.chunks(3);
+        for payment in unused {
+            let refund = Payment {
+                mint: payment.mint,
+                amount: self.metaverf_account.refund_for(payment.amount)?,
+            };
+
+            if payment.mint == CollegeAccount::PAID_IN_SOL {
+                self.refund_sol(refund.amount)?;
+            } else {
+                let accounts = refund_accounts.next().ok_or(CertificateError::InvalidRefundAccounts)?;
+                self.refund_tokens(&refund, accounts)?;
+            }
+
+            refunds.push(refund);
+        }
+
+        self.tombstone.set_inner(CollegeTombstone {
+            college_id: self.college_account.college_id,
+            authority: self.college_authority.key(),
+            closed_at: now,
+            collections: self.college_account.collections.iter().map(|c| c.collection).collect(),
+            certificates_issued: self.college_account.certificates_issued,
+            bump: bumps.tombstone,
+        });
+
+        emit!(CollegeClosed {
+            college_id: self.college_account.college_id,
+            refunds,
+            closed_at: now,
+        });
+
+        Ok(())
+    }
+
+    fn refund_sol(&self, lamports: u64) -> Result<()> {
+        if lamports == 0 {
+            return Ok(());
+        }
+
+        let sol_vault = self.sol_vault.as_ref().ok_or(CertificateError::MissingSolVault)?;
+
+        transfer_from_sol_vault(
+            &sol_vault.to_account_info(),
+            &self.college_authority.to_account_info(),
+            &self.system_program.to_account_info(),
+            lamports,
+        )
+    }
+
+    fn refund_tokens(&self, refund: &Payment, accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        let (Some(token_program), [mint, treasury, college_token_account]) = (self.token_program.as_ref(), accounts) else {
+            return err!(CertificateError::InvalidRefundAccounts);
+        };
+
+        let mint_account = InterfaceAccount::<Mint>::try_from(mint)?;
+        let college_tokens = InterfaceAccount::<TokenAccount>::try_from(college_token_account)?;
+        let expected_treasury = get_associated_token_address_with_program_id(
+            &self.metaverf_account.key(),
+            &refund.mint,
+            &token_program.key(),
+        );
+        require!(
+            mint.key() == refund.mint
+                && treasury.key() == expected_treasury
+                && college_tokens.mint == refund.mint
+                && college_tokens.owner == self.college_authority.key(),
+            CertificateError::InvalidRefundAccounts
+        );
+
+        if refund.amount == 0 {
+            return Ok(());
+        }
+
+        let seeds = &[b"protocol".as_ref(), &[self.metaverf_account.verf_bump]];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = TransferChecked {
+            from: treasury.clone(),
+            mint: mint.clone(),
+            to: college_token_account.clone(),
+            authority: self.metaverf_account.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+        transfer_checked(cpi_ctx, refund.amount, mint_account.decimals)
+    }
+}
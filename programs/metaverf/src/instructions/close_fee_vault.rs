@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface},
+    token::{transfer_checked, TransferChecked},
+};
+
+use crate::state::CollegeAccount;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct CloseFeeVault<'info> {
+    #[account(mut)]
+    pub college_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
+        constraint = college_account.fee_vault_mint == Some(mint_usdc.key()) @ CertificateError::InvalidPaymentMint,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    pub mint_usdc: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_vault", college_account.key().as_ref()],
+        bump,
+        token::mint = mint_usdc,
+        token::authority = college_account,
+        token::token_program = token_program,
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_usdc,
+        associated_token::authority = college_authority
+    )]
+    pub college_token_account: InterfaceAccount<'info, TokenAccount>,//Receives whatever is left in the vault
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CloseFeeVault<'info> {
+    pub fn close_fee_vault(&mut self) -> Result<()> {
+        let id = self.college_account.college_id.to_le_bytes();
+        let seeds = &[b"college".as_ref(), id.as_ref(), &[self.college_account.bump]];
+        let signer = &[&seeds[..]];
+
+        if self.fee_vault.amount > 0 {
+            let cpi_accounts = TransferChecked {
+                from: self.fee_vault.to_account_info(),
+                mint: self.mint_usdc.to_account_info(),
+                to: self.college_token_account.to_account_info(),
+                authority: self.college_account.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer);
+            transfer_checked(cpi_ctx, self.fee_vault.amount, self.mint_usdc.decimals)?;
+        }
+
+        let cpi_accounts = CloseAccount {
+            account: self.fee_vault.to_account_info(),
+            destination: self.college_authority.to_account_info(),
+            authority: self.college_account.to_account_info(),
+        };
+        close_account(CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer))?;
+
+        self.college_account.fee_vault_mint = None;
+
+        Ok(())
+    }
+}
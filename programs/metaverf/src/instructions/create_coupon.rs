@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::{Coupon, CouponDiscount, MetaverfAccount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(code: String)]
+pub struct CreateCoupon<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"coupon", code.as_bytes()],
+        bump,
+        space = 8 + Coupon::INIT_SPACE,
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateCoupon<'info> {
+    pub fn create_coupon(
+        &mut self,
+        code: String,
+        discount: CouponDiscount,
+        max_uses: u32,
+        expires_at: i64,
+        allowed_colleges: Vec<u64>,
+        bumps: &CreateCouponBumps,
+    ) -> Result<()> {
+        require!(
+            !code.is_empty() && code.len() <= Coupon::MAX_CODE_LEN,
+            CertificateError::InvalidCoupon
+        );
+        require!(
+            allowed_colleges.len() <= Coupon::MAX_ALLOWED_COLLEGES,
+            CertificateError::InvalidCoupon
+        );
+        if let CouponDiscount::Percent { bps } = discount {
+            require!(bps <= 10_000, CertificateError::InvalidCoupon);
+        }
+
+        self.coupon.set_inner(Coupon {
+            code,
+            discount,
+            max_uses,
+            uses: 0,
+            expires_at,
+            allowed_colleges,
+            active: !self.metaverf_account.council_enabled(),
+            bump: bumps.coupon,
+        });
+
+        Ok(())
+    }
+}
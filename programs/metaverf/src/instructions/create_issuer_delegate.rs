@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{CollegeAccount, IssuerDelegate, IssuerDelegateArgs};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64, args: IssuerDelegateArgs)]
+pub struct CreateIssuerDelegate<'info> {
+    #[account(mut)]
+    pub college_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        init,
+        payer = college_authority,
+        seeds = [b"issuer_delegate", college_account.key().as_ref(), args.delegate.as_ref()],
+        bump,
+        space = 8 + IssuerDelegate::INIT_SPACE
+    )]
+    pub issuer_delegate: Account<'info, IssuerDelegate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateIssuerDelegate<'info> {
+    pub fn create_issuer_delegate(&mut self, args: IssuerDelegateArgs, bumps: &CreateIssuerDelegateBumps) -> Result<()> {
+        require!(
+            args.collections
+                .iter()
+                .all(|key| self.college_account.collections.iter().any(|c| c.collection == *key)),
+            CertificateError::CollectionNotFound
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.issuer_delegate.set_inner(IssuerDelegate::new(
+            self.college_account.key(),
+            self.college_authority.key(),
+            args,
+            now,
+            bumps.issuer_delegate,
+        )?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{CollegeAccount, MetaverfAccount, Plan};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(plan_id: u8)]
+pub struct CreatePlan<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"plan", plan_id.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + Plan::INIT_SPACE,
+    )]
+    pub plan: Account<'info, Plan>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreatePlan<'info> {
+    pub fn create_plan(
+        &mut self,
+        plan_id: u8,
+        name: String,
+        price: u64,
+        max_collections: u8,
+        yearly_certificate_quota: u32,
+        bumps: &CreatePlanBumps,
+    ) -> Result<()> {
+        require!(name.len() <= Plan::MAX_NAME_LEN, CertificateError::InvalidPlan);
+        require!(
+            max_collections as usize <= CollegeAccount::MAX_COLLECTIONS,
+            CertificateError::InvalidPlan
+        );
+
+        // Like accepted mints, plans created in council mode need a council proposal to go live
+        self.plan.set_inner(Plan {
+            id: plan_id,
+            name,
+            price,
+            pending_price: None,
+            price_effective_at: 0,
+            max_collections,
+            yearly_certificate_quota,
+            active: !self.metaverf_account.council_enabled(),
+            bump: bumps.plan,
+        });
+
+        Ok(())
+    }
+}
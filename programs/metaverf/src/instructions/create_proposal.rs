@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{CouncilProposal, MetaverfAccount, ProposalAction};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = metaverf_account.council_enabled() @ CertificateError::CouncilModeInactive,
+        constraint = metaverf_account.is_council_member(&proposer.key()) @ CertificateError::NotCouncilMember,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [b"proposal", metaverf_account.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + CouncilProposal::INIT_SPACE,
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateProposal<'info> {
+    pub fn create_proposal(&mut self, action: ProposalAction, bumps: &CreateProposalBumps) -> Result<()> {
+        // The proposer's approval is counted straight away
+        self.proposal.set_inner(CouncilProposal {
+            id: self.metaverf_account.proposal_count,
+            proposer: self.proposer.key(),
+            action,
+            approvals: vec![self.proposer.key()],
+            bump: bumps.proposal,
+        });
+
+        self.metaverf_account.proposal_count += 1;
+
+        Ok(())
+    }
+}
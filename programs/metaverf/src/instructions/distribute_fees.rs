@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer_checked, TransferChecked};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::{AcceptedMint, MetaverfAccount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct DistributeFees<'info> {
+    #[account(
+        constraint = mint_usdc.key() == metaverf_account.payment_mint || accepted_mint.is_some() @ CertificateError::InvalidPaymentMint,
+    )]
+    pub mint_usdc: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"accepted_mint", mint_usdc.key().as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,//Only needed when distributing an accepted mint
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = !metaverf_account.payout_recipients.is_empty() @ CertificateError::PayoutNotConfigured,
+        constraint = !metaverf_account.paused @ CertificateError::ProtocolPaused,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_usdc,
+        associated_token::authority = metaverf_account
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DistributeFees<'info> {
+    // Permissionless. `recipient_accounts` are token accounts for `mint_usdc`, one per payout recipient
+    // in the configured order. Rounding dust stays in the treasury for the next distribution.
+    pub fn distribute_fees(&mut self, recipient_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        let recipients = &self.metaverf_account.payout_recipients;
+        require!(
+            recipient_accounts.len() == recipients.len(),
+            CertificateError::InvalidPayoutAccount
+        );
+
+        let balance = self.treasury.amount;
+
+        let seeds = &[b"protocol".as_ref(),
+         &[self.metaverf_account.verf_bump]];
+
+        let signer = &[&seeds[..]];
+
+        for (recipient, account) in recipients.iter().zip(recipient_accounts) {
+            let token_account = InterfaceAccount::<TokenAccount>::try_from(account)?;
+            require!(
+                token_account.owner == recipient.owner && token_account.mint == self.mint_usdc.key(),
+                CertificateError::InvalidPayoutAccount
+            );
+
+            let share = (balance as u128 * recipient.share_bps as u128 / 10_000) as u64;
+            if share == 0 {
+                continue;
+            }
+
+            let cpi_accounts = TransferChecked {
+                from: self.treasury.to_account_info(),
+                mint: self.mint_usdc.to_account_info(),
+                to: account.clone(),
+                authority: self.metaverf_account.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer);
+
+            transfer_checked(cpi_ctx, share, self.mint_usdc.decimals)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use crate::state::{AcceptedMint, CollegeAccount, CouncilProposal, Coupon, MetaverfAccount, PendingParameters, Plan, ProposalAction};
+use crate::error::CertificateError;
+use crate::utils::transfer_from_sol_vault;
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = metaverf_account.council_enabled() @ CertificateError::CouncilModeInactive,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = proposer,
+        close = proposer,
+        constraint = proposal.approvals_from(&metaverf_account.council) >= metaverf_account.council_threshold as usize @ CertificateError::ProposalThresholdNotMet,
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_parameters"],
+        bump = pending_parameters.bump
+    )]
+    pub pending_parameters: Account<'info, PendingParameters>,
+
+    #[account(
+        mut,
+        seeds = [b"accepted_mint", accepted_mint.mint.as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,//Only needed for `UpdateAcceptedMint`
+
+    #[account(
+        mut,
+        seeds = [b"plan", plan.id.to_le_bytes().as_ref()],
+        bump = plan.bump
+    )]
+    pub plan: Option<Account<'info, Plan>>,//Only needed for `UpdatePlan`
+
+    #[account(
+        mut,
+        seeds = [b"coupon", coupon.code.as_bytes()],
+        bump = coupon.bump
+    )]
+    pub coupon: Option<Account<'info, Coupon>>,//Only needed for `SetCouponActive`
+
+    #[account(
+        mut,
+        seeds = [b"sol_vault"],
+        bump,
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,//Only needed for `WithdrawSol`
+
+    #[account(mut)]
+    pub sol_destination: Option<SystemAccount<'info>>,//Only needed for `WithdrawSol`
+
+    pub system_program: Option<Program<'info, System>>,//Only needed for `WithdrawSol`
+}
+
+impl<'info> ExecuteProposal<'info> {
+    // Runs every action except `WithdrawFees`, which needs the treasury accounts of `ExecuteWithdrawProposal`
+    pub fn execute_proposal(&mut self) -> Result<()> {
+        match self.proposal.action.clone() {
+            ProposalAction::UpdateParameters { annual_fee, subscription_duration, grace_period, certificate_fee } => {
+                require!(grace_period.is_none_or(|grace| grace >= 0), CertificateError::InvalidGracePeriod);
+
+                let effective_at = Clock::get()?.unix_timestamp + self.metaverf_account.parameter_delay;
+                self.pending_parameters.queue(annual_fee, subscription_duration, grace_period, certificate_fee, effective_at)?;
+            }
+            ProposalAction::SetCouncil { members, threshold } => {
+                self.metaverf_account.set_council(members, threshold)?;
+            }
+            ProposalAction::UpdateAcceptedMint { mint, annual_fee, enabled } => {
+                let effective_at = Clock::get()?.unix_timestamp + self.metaverf_account.parameter_delay;
+                let accepted_mint = self
+                    .accepted_mint
+                    .as_mut()
+                    .filter(|accepted| accepted.mint == mint)
+                    .ok_or(CertificateError::InvalidAcceptedMint)?;
+                accepted_mint.update(annual_fee, enabled, effective_at);
+            }
+            ProposalAction::SetPayoutRecipients { recipients } => {
+                self.metaverf_account.set_payout_recipients(recipients)?;
+            }
+            ProposalAction::SetPrepayDiscounts { discounts } => {
+                self.metaverf_account.set_prepay_discounts(discounts)?;
+            }
+            ProposalAction::SetRefundPolicy { refund_bps } => {
+                self.metaverf_account.set_refund_policy(refund_bps)?;
+            }
+            ProposalAction::SetCouponActive { code, active } => {
+                let coupon = self
+                    .coupon
+                    .as_mut()
+                    .filter(|coupon| coupon.code == code)
+                    .ok_or(CertificateError::InvalidCoupon)?;
+                coupon.active = active;
+            }
+            ProposalAction::SetSolPriceFeed { feed, max_age, max_confidence_bps } => {
+                self.metaverf_account.set_sol_price_feed(feed, max_age, max_confidence_bps)?;
+            }
+            ProposalAction::WithdrawSol { amount, destination } => {
+                let (Some(sol_vault), Some(sol_destination), Some(system_program)) =
+                    (self.sol_vault.as_ref(), self.sol_destination.as_ref(), self.system_program.as_ref())
+                else {
+                    return err!(CertificateError::InvalidProposalAction);
+                };
+                require_keys_eq!(sol_destination.key(), destination, CertificateError::InvalidWithdrawDestination);
+                require!(
+                    self.metaverf_account.can_withdraw_to(&destination),
+                    CertificateError::ProtocolPaused
+                );
+
+                transfer_from_sol_vault(
+                    &sol_vault.to_account_info(),
+                    &sol_destination.to_account_info(),
+                    &system_program.to_account_info(),
+                    amount,
+                )?;
+            }
+            ProposalAction::UpdatePlan { plan_id, price, max_collections, yearly_certificate_quota, active } => {
+                require!(
+                    max_collections.is_none_or(|max| max as usize <= CollegeAccount::MAX_COLLECTIONS),
+                    CertificateError::InvalidPlan
+                );
+
+                let effective_at = Clock::get()?.unix_timestamp + self.metaverf_account.parameter_delay;
+                let plan = self
+                    .plan
+                    .as_mut()
+                    .filter(|plan| plan.id == plan_id)
+                    .ok_or(CertificateError::InvalidPlan)?;
+                plan.update(price, max_collections, yearly_certificate_quota, active, effective_at);
+            }
+            ProposalAction::WithdrawFees { .. } => {
+                return err!(CertificateError::InvalidProposalAction);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer_checked, TransferChecked};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::{AcceptedMint, CouncilProposal, MetaverfAccount, ProposalAction};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawProposal<'info> {
+    #[account(
+        constraint = mint_usdc.key() == metaverf_account.payment_mint || accepted_mint.is_some() @ CertificateError::InvalidPaymentMint,
+    )]
+    pub mint_usdc: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"accepted_mint", mint_usdc.key().as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,//Only needed when withdrawing an accepted mint
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = metaverf_account.council_enabled() @ CertificateError::CouncilModeInactive,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = proposer,
+        close = proposer,
+        constraint = proposal.approvals_from(&metaverf_account.council) >= metaverf_account.council_threshold as usize @ CertificateError::ProposalThresholdNotMet,
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_usdc,
+        associated_token::authority = metaverf_account
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint_usdc,
+        constraint = metaverf_account.can_withdraw_to(&destination.owner) @ CertificateError::ProtocolPaused,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ExecuteWithdrawProposal<'info> {
+    pub fn execute_withdraw_proposal(&mut self) -> Result<()> {
+        let ProposalAction::WithdrawFees { amount, destination } = self.proposal.action else {
+            return err!(CertificateError::InvalidProposalAction);
+        };
+
+        require_keys_eq!(
+            self.destination.key(),
+            destination,
+            CertificateError::InvalidWithdrawDestination
+        );
+
+        let seeds = &[b"protocol".as_ref(),
+         &[self.metaverf_account.verf_bump]];
+
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = TransferChecked {
+            from: self.treasury.to_account_info(),
+            mint: self.mint_usdc.to_account_info(),
+            to: self.destination.to_account_info(),
+            authority: self.metaverf_account.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer);
+
+        transfer_checked(cpi_ctx, amount, self.mint_usdc.decimals)?;
+        Ok(())
+    }
+}
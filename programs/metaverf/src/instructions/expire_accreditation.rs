@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{Accreditation, AccreditationStatus, Accreditor, CollegeAccount};
+use crate::error::CertificateError;
+use crate::events::AccreditationChanged;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct ExpireAccreditation<'info> {
+    #[account(
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(address = accreditation.accreditor)]
+    pub accreditor: Account<'info, Accreditor>,//The accreditor that attested, checked for being deactivated
+
+    #[account(
+        mut,
+        seeds = [b"accreditation", accreditation.accreditor.as_ref(), college_account.key().as_ref(), accreditation.programme.as_bytes()],
+        bump = accreditation.bump,
+        constraint = accreditation.status == AccreditationStatus::Accredited @ CertificateError::AccreditationNotActive,
+    )]
+    pub accreditation: Account<'info, Accreditation>,
+}
+
+impl<'info> ExpireAccreditation<'info> {
+    // Permissionless crank, anyone can mark an accreditation that is no longer valid: expired once
+    // `expires_at` has passed, revoked once its accreditor was deactivated
+    pub fn expire_accreditation(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !self.accreditation.is_valid(&self.accreditor, now),
+            CertificateError::AccreditationStillValid
+        );
+
+        let status = if self.accreditation.is_lapsed(now) {
+            AccreditationStatus::Expired
+        } else {
+            self.accreditation.revoked_at = now;
+            AccreditationStatus::Revoked
+        };
+        self.accreditation.status = status;
+
+        emit!(AccreditationChanged {
+            college_id: self.college_account.college_id,
+            accreditor: self.accreditation.accreditor,
+            programme: self.accreditation.programme.clone(),
+            status,
+            expires_at: self.accreditation.expires_at,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::{CollegeAccount, DeactivationReason, MetaverfAccount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct ExpireCollege<'info> {
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"college", college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.active @ CertificateError::CollegeNotActive,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+}
+
+impl<'info> ExpireCollege<'info> {
+    // Permissionless crank, anyone can deactivate a college once it is past expiry and grace period
+    pub fn expire_college(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            self.college_account.is_lapsed(&self.metaverf_account, now),
+            CertificateError::SubscriptionNotLapsed
+        );
+
+        self.college_account.deactivate(DeactivationReason::Expired, now);
+
+        Ok(())
+    }
+}
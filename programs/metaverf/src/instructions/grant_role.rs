@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{MetaverfAccount, Role, RoleAssignment};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(role: Role, holder: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"role", role.seed(), holder.as_ref()],
+        bump,
+        space = 8 + RoleAssignment::INIT_SPACE,
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GrantRole<'info> {
+    pub fn grant_role(&mut self, role: Role, holder: Pubkey, bumps: &GrantRoleBumps) -> Result<()> {
+        self.role_assignment.set_inner(RoleAssignment {
+            role,
+            holder,
+            bump: bumps.role_assignment,
+        });
+
+        Ok(())
+    }
+}
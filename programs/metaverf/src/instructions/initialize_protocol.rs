@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::MetaverfAccount;
+use crate::state::{MetaverfAccount, PendingParameters};
+use crate::error::CertificateError;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{Mint, TokenAccount, TokenInterface},
@@ -30,6 +31,15 @@ pub struct InitializeProtocol<'info> {
     )]
     pub treasury: InterfaceAccount<'info, TokenAccount>,//The Treasury of the Protocol where all the Money will get stored
 
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"pending_parameters"],
+        bump,
+        space = 8 + PendingParameters::INIT_SPACE,
+    )]
+    pub pending_parameters: Account<'info, PendingParameters>,//The queue for timelocked parameter changes
+
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
@@ -40,14 +50,45 @@ impl<'info> InitializeProtocol<'info> {
         &mut self,
         annual_fee: u64,//the price of listing your college in my protocol
         subscription_duration: i64,//for how much time 
+        parameter_delay: i64,//how long a parameter change waits before it can be applied
+        grace_period: i64,//how long a lapsed college may keep issuing
         bumps: &InitializeProtocolBumps,
     ) -> Result<()> {
+        require!(parameter_delay >= 0, CertificateError::InvalidParameterDelay);
+        require!(subscription_duration > 0, CertificateError::InvalidSubscriptionDuration);
+        require!(grace_period >= 0, CertificateError::InvalidGracePeriod);
+
         self.metaverf_account.set_inner(MetaverfAccount {
-            // admin_key: self.admin.key(),
-            uni_no: 0,
             subscription_duration,
             verf_bump: bumps.metaverf_account,
             annual_fee,
+            admin: self.admin.key(),
+            pending_admin: None,
+            council: Vec::new(),
+            council_threshold: 0,
+            proposal_count: 0,
+            parameter_delay,
+            paused: false,
+            payment_mint: self.mint_usdc.key(),
+            version: MetaverfAccount::VERSION,
+            payout_recipients: Vec::new(),
+            grace_period,
+            prepay_discounts: Vec::new(),
+            certificate_fee: 0,
+            refund_bps: 0,
+            sol_price_feed: Pubkey::default(),
+            max_price_age: 0,
+            max_price_confidence_bps: 0,
+            next_college_id: MetaverfAccount::FIRST_COLLEGE_ID,
+        });
+
+        self.pending_parameters.set_inner(PendingParameters {
+            annual_fee: None,
+            subscription_duration: None,
+            effective_at: 0,
+            bump: bumps.pending_parameters,
+            grace_period: None,
+            certificate_fee: None,
         });
 
         Ok(())
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_lang::error::ErrorCode;
+use anchor_lang::Discriminator;
+
+use crate::state::{CollegeAccount, MetaverfAccount};
+use crate::error::CertificateError;
+use crate::utils::{grow_account, rewrite_account};
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct MigrateCollege<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,//Anyone can migrate a college, they only pay the extra rent
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,//Must be migrated first
+
+    #[account(
+        mut,
+        seeds = [b"college", college_id.to_le_bytes().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    ///CHECK: Deserialized by hand, older layouts may be shorter than `CollegeAccount`
+    pub college_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateCollege<'info> {
+    pub fn migrate_college(&mut self) -> Result<()> {
+        let info = self.college_account.to_account_info();
+        require!(
+            info.try_borrow_data()?.starts_with(&CollegeAccount::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        let mut college = CollegeAccount::deserialize_any_version(&info.try_borrow_data()?)?;
+        require!(college.version < CollegeAccount::VERSION, CertificateError::AlreadyMigrated);
+
+        grow_account(
+            &info,
+            8 + CollegeAccount::INIT_SPACE,
+            &self.payer.to_account_info(),
+            &self.system_program.to_account_info(),
+        )?;
+
+        college.upgrade(&self.metaverf_account);
+        rewrite_account(&info, &college)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_lang::error::ErrorCode;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_lang::Discriminator;
+
+use crate::state::{CollegeAccount, CollegeLookup, CollegeProfile, MetaverfAccount};
+use crate::utils::close_program_account;
+
+#[derive(Accounts)]
+#[instruction(legacy_id: u16)]
+pub struct MigrateLegacyCollege<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,//Anyone can move a college, they fund the new accounts and get the old rent back
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,//Must be migrated first
+
+    #[account(
+        mut,
+        seeds = [b"college", legacy_id.to_le_bytes().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    ///CHECK: Deserialized by hand, the only place the two-byte seed of caller-picked IDs is still used
+    pub legacy_college: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"college", (legacy_id as u64).to_le_bytes().as_ref()],
+        bump,
+        space = 8 + CollegeAccount::INIT_SPACE,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(mut)]
+    ///CHECK: Seeded by the authority stored in `legacy_college`, so checked and created in the handler
+    pub college_lookup: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"college_profile", college_account.key().as_ref()],
+        bump,
+        space = 8 + CollegeProfile::INIT_SPACE
+    )]
+    pub college_profile: Account<'info, CollegeProfile>,//Left empty, the authority fills it with `update_college_profile`
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateLegacyCollege<'info> {
+    // Moves a college registered with a caller-picked u16 ID to the address seeded by its u64 ID.
+    // Baseline colleges never had a fee vault, a lookup or a profile, so only the latter two are created.
+    pub fn migrate_legacy_college(&mut self, bumps: &MigrateLegacyCollegeBumps) -> Result<()> {
+        let info = self.legacy_college.to_account_info();
+        require!(
+            info.try_borrow_data()?.starts_with(&CollegeAccount::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        let mut college = CollegeAccount::deserialize_any_version(&info.try_borrow_data()?)?;
+        college.upgrade(&self.metaverf_account);
+        college.bump = bumps.college_account;
+
+        self.create_college_lookup(&college)?;
+        self.college_profile.college = self.college_account.key();
+        self.college_profile.bump = bumps.college_profile;
+        self.college_account.set_inner(college);
+
+        close_program_account(&info, &self.payer.to_account_info())
+    }
+
+    // An authority that has since registered another college keeps the lookup pointing to that one
+    fn create_college_lookup(&self, college: &CollegeAccount) -> Result<()> {
+        let info = self.college_lookup.to_account_info();
+        let (address, bump) =
+            Pubkey::find_program_address(&[b"college_lookup", college.authority.as_ref()], &crate::ID);
+        require_keys_eq!(info.key(), address, ErrorCode::ConstraintSeeds);
+
+        if !info.data_is_empty() {
+            return Ok(());
+        }
+
+        let space = 8 + CollegeLookup::INIT_SPACE;
+        let signer: &[&[&[u8]]] = &[&[b"college_lookup", college.authority.as_ref(), &[bump]]];
+
+        create_account(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.payer.to_account_info(),
+                    to: info.clone(),
+                },
+                signer,
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &crate::ID,
+        )?;
+
+        let lookup = CollegeLookup {
+            college_id: college.college_id,
+            college: self.college_account.key(),
+            bump,
+        };
+
+        lookup.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_lang::error::ErrorCode;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::program::Metaverf;
+use crate::state::{MetaverfAccount, PendingParameters};
+use crate::error::CertificateError;
+use crate::utils::{grow_account, rewrite_account};
+
+#[derive(Accounts)]
+pub struct MigrateProtocol<'info> {
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,//Becomes the admin of a baseline protocol
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump,
+        owner = crate::ID,
+    )]
+    ///CHECK: Deserialized by hand, the baseline layout is shorter than `MetaverfAccount`
+    pub metaverf_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_parameters"],
+        bump,
+    )]
+    ///CHECK: Created here, the baseline protocol had none
+    pub pending_parameters: UncheckedAccount<'info>,
+
+    pub mint_usdc: InterfaceAccount<'info, Mint>,//Pinned as the payment mint
+
+    #[account(
+        associated_token::mint = mint_usdc,
+        associated_token::authority = metaverf_account
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,//The baseline treasury, ties `mint_usdc` to the mint fees were paid in
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Metaverf>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ CertificateError::NotUpgradeAuthority)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateProtocol<'info> {
+    pub fn migrate_protocol(&mut self, bumps: &MigrateProtocolBumps) -> Result<()> {
+        let info = self.metaverf_account.to_account_info();
+        require!(
+            info.try_borrow_data()?.starts_with(&MetaverfAccount::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        let mut protocol = MetaverfAccount::deserialize_any_version(&info.try_borrow_data()?)?;
+        require!(protocol.version < MetaverfAccount::VERSION, CertificateError::AlreadyMigrated);
+
+        grow_account(
+            &info,
+            8 + MetaverfAccount::INIT_SPACE,
+            &self.upgrade_authority.to_account_info(),
+            &self.system_program.to_account_info(),
+        )?;
+
+        if protocol.version < 1 {
+            self.create_pending_parameters(bumps.pending_parameters)?;
+        }
+
+        protocol.upgrade(self.upgrade_authority.key(), self.mint_usdc.key());
+        rewrite_account(&info, &protocol)?;
+
+        Ok(())
+    }
+
+    fn create_pending_parameters(&self, bump: u8) -> Result<()> {
+        let info = self.pending_parameters.to_account_info();
+        let space = 8 + PendingParameters::INIT_SPACE;
+        let signer: &[&[&[u8]]] = &[&[b"pending_parameters", &[bump]]];
+
+        create_account(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.upgrade_authority.to_account_info(),
+                    to: info.clone(),
+                },
+                signer,
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &crate::ID,
+        )?;
+
+        let pending = PendingParameters {
+            annual_fee: None,
+            subscription_duration: None,
+            effective_at: 0,
+            bump,
+            grace_period: None,
+            certificate_fee: None,
+        };
+
+        pending.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
@@ -5,8 +5,12 @@ use mpl_core::{
     types::{DataState, PermanentFreezeDelegate, Plugin, PluginAuthorityPair, Attributes, Attribute, PluginAuthority},
     ID as MPL_CORE_ID,
 };
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface},
+    token::{transfer_checked, TransferChecked},
+};
 
-use crate::state::CollegeAccount;
+use crate::state::{CollegeAccount, IssuerDelegate, MetaverfAccount, Plan};
 // use crate::college::CollectionInfo;
 use crate::error::CertificateError;
 // CreateV1CpiBuilder
@@ -21,21 +25,62 @@ pub struct CertificateArgs {
 }
 
 #[derive(Accounts)]
-#[instruction(college_id: u16)]
+#[instruction(college_id: u64)]
 pub struct MintCertificate<'info> {
     
     #[account(mut)]
-    pub college_authority: Signer<'info>,
+    pub issuer: Signer<'info>,//The college authority or a staff key with an issuer delegate
 
     #[account(
         mut,
-        seeds = [b"college", college_account.id.to_le_bytes().as_ref()],
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
         bump = college_account.bump,
-        constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
+        constraint = college_account.authority == issuer.key() || issuer_delegate.is_some() @ CertificateError::NotAuthorized,
         constraint = college_account.active @ CertificateError::CollegeNotActive,
     )]
     pub college_account: Account<'info, CollegeAccount>, //The college account which has the Initial Collection
 
+    #[account(
+        mut,
+        seeds = [b"issuer_delegate", college_account.key().as_ref(), issuer.key().as_ref()],
+        bump = issuer_delegate.bump,
+        constraint = issuer_delegate.granted_by == college_account.authority @ CertificateError::StaleIssuerDelegate,
+    )]
+    pub issuer_delegate: Option<Account<'info, IssuerDelegate>>,//Only needed when a staff key mints
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = !metaverf_account.paused @ CertificateError::ProtocolPaused,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        seeds = [b"plan", plan.id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        constraint = college_account.plan == Some(plan.key()) @ CertificateError::InvalidPlan,
+    )]
+    pub plan: Option<Account<'info, Plan>>,//Must be passed when the college is on a plan
+
+    #[account(address = metaverf_account.payment_mint @ CertificateError::InvalidPaymentMint)]
+    pub mint_usdc: Option<InterfaceAccount<'info, Mint>>,//The payment accounts are only needed while `certificate_fee` is set
+
+    #[account(
+        mut,
+        associated_token::mint = mint_usdc,
+        associated_token::authority = metaverf_account
+    )]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_usdc,
+        associated_token::authority = issuer
+    )]
+    pub payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,//The issuer pays the fee, a staff key holds the college's fee float
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     #[account(
         mut,
         constraint = college_account.collections.iter().any(|c| c.collection == collection.key()) @ CertificateError::CollectionNotFound,
@@ -58,6 +103,26 @@ pub struct MintCertificate<'info> {
 
 impl<'info> MintCertificate<'info> {
     pub fn mint_certificate(&mut self,args: CertificateArgs) -> Result<()> {
+        // Deactivating a lapsed college is left to `expire_college`
+        let now = Clock::get()?.unix_timestamp;
+        if self.college_account.is_lapsed(&self.metaverf_account, now) {
+            return err!(CertificateError::SubscriptionExpired);
+        }
+
+        self.college_account.require_plan(self.plan.as_deref())?;
+        self.college_account.roll_usage_period(&self.metaverf_account, now);
+        if let Some(plan) = self.plan.as_deref() {
+            require!(
+                plan.allows_certificate(self.college_account.certificates_this_period),
+                CertificateError::CertificateQuotaExceeded
+            );
+        }
+        if let Some(issuer_delegate) = self.issuer_delegate.as_mut() {
+            issuer_delegate.record_certificate(&self.collection.key(), now)?;
+        }
+        self.charge_certificate_fee()?;
+        self.college_account.record_certificate()?;
+
         // Create attributes for the certificate NFT
         let mut attribute_list: Vec<Attribute> = vec![
             Attribute {
@@ -74,7 +139,7 @@ impl<'info> MintCertificate<'info> {
             },
             Attribute {
                 key: "College ID".to_string(),
-                value: self.college_account.id.to_string(),
+                value: self.college_account.college_id.to_string(),
             },
             Attribute {
                 key: "Certificate Type".to_string(),
@@ -90,12 +155,26 @@ impl<'info> MintCertificate<'info> {
             });
         }
 
+        // Collections created before the college PDA became their update authority are still
+        // signed for by the college authority that created them, so delegates cannot mint into them
+        let college_owned = self.collection.update_authority == self.college_account.key();
+        let authority = if college_owned {
+            self.college_account.to_account_info()
+        } else {
+            require_keys_eq!(self.issuer.key(), self.college_account.authority, CertificateError::NotAuthorized);
+            self.issuer.to_account_info()
+        };
+
+        let id = self.college_account.college_id.to_le_bytes();
+        let seeds = &[b"college".as_ref(), id.as_ref(), &[self.college_account.bump]];
+        let signer = &[&seeds[..]];
+
         // Create the certificate NFT with PermanentFreezeDelegate to ensure immutability
         CreateV2CpiBuilder::new(&self.mpl_core_program.to_account_info())
             .asset(&self.asset.to_account_info())
             .collection(Some(&self.collection.to_account_info()))
-            .authority(Some(&self.college_authority.to_account_info()))
-            .payer(&self.college_authority.to_account_info())
+            .authority(Some(&authority))
+            .payer(&self.issuer.to_account_info())
             .owner(Some(&self.student_wallet.to_account_info()))
             .system_program(&self.system_program.to_account_info())
             .data_state(DataState::AccountState)
@@ -113,8 +192,35 @@ impl<'info> MintCertificate<'info> {
                     authority: Some(PluginAuthority::UpdateAuthority),
                 },
             ])
-            .invoke()?;
+            .invoke_signed(signer)?;
 
         Ok(())
     }
+
+    // Billed to the signing issuer rather than the college authority, whose tokens a staff key cannot move
+    fn charge_certificate_fee(&self) -> Result<()> {
+        let fee = self.metaverf_account.certificate_fee;
+        if fee == 0 {
+            return Ok(());
+        }
+
+        let (Some(mint_usdc), Some(treasury), Some(payer_token_account), Some(token_program)) = (
+            self.mint_usdc.as_ref(),
+            self.treasury.as_ref(),
+            self.payer_token_account.as_ref(),
+            self.token_program.as_ref(),
+        ) else {
+            return err!(CertificateError::MissingFeeAccounts);
+        };
+
+        let cpi_accounts = TransferChecked {
+            from: payer_token_account.to_account_info(),
+            mint: mint_usdc.to_account_info(),
+            to: treasury.to_account_info(),
+            authority: self.issuer.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+        transfer_checked(cpi_ctx, fee, mint_usdc.decimals)
+    }
 }
\ No newline at end of file
@@ -3,16 +3,118 @@ pub mod initialize_protocol;
 pub mod register_college;
 pub mod renew_subscription;
 pub mod update_parameter;
+pub mod apply_parameters;
 pub mod withdraw_fees;
 pub mod add_collection;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod cancel_admin_transfer;
+pub mod set_council;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod execute_withdraw_proposal;
+pub mod pause_protocol;
+pub mod add_accepted_mint;
+pub mod update_accepted_mint;
+pub mod apply_accepted_mint_fee;
+pub mod migrate_protocol;
+pub mod migrate_college;
+pub mod migrate_legacy_college;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_college_active;
+pub mod set_payout_recipients;
+pub mod distribute_fees;
+pub mod expire_college;
+pub mod set_prepay_discounts;
+pub mod create_plan;
+pub mod update_plan;
+pub mod apply_plan_price;
+pub mod change_plan;
+pub mod set_refund_policy;
+pub mod close_college;
+pub mod open_fee_vault;
+pub mod close_fee_vault;
+pub mod auto_renew;
+pub mod create_coupon;
+pub mod set_coupon_active;
+pub mod set_sol_price_feed;
+pub mod register_college_with_sol;
+pub mod renew_subscription_with_sol;
+pub mod withdraw_sol;
+pub mod update_college_profile;
+pub mod propose_college_authority;
+pub mod accept_college_authority;
+pub mod cancel_college_authority_transfer;
+pub mod set_college_update_authority;
+pub mod update_collection;
+pub mod create_issuer_delegate;
+pub mod revoke_issuer_delegate;
+pub mod register_accreditor;
+pub mod set_accreditor_active;
+pub mod attest_accreditation;
+pub mod revoke_accreditation;
+pub mod expire_accreditation;
 
 pub use mint_certificates::*;
 pub use initialize_protocol::*;
 pub use register_college::*;
 pub use renew_subscription::*;
 pub use update_parameter::*;
+pub use apply_parameters::*;
 pub use withdraw_fees::*;
 pub use add_collection::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use cancel_admin_transfer::*;
+pub use set_council::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use execute_proposal::*;
+pub use execute_withdraw_proposal::*;
+pub use pause_protocol::*;
+pub use add_accepted_mint::*;
+pub use update_accepted_mint::*;
+pub use apply_accepted_mint_fee::*;
+pub use migrate_protocol::*;
+pub use migrate_college::*;
+pub use migrate_legacy_college::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use set_college_active::*;
+pub use set_payout_recipients::*;
+pub use distribute_fees::*;
+pub use expire_college::*;
+pub use set_prepay_discounts::*;
+pub use create_plan::*;
+pub use update_plan::*;
+pub use apply_plan_price::*;
+pub use change_plan::*;
+pub use set_refund_policy::*;
+pub use close_college::*;
+pub use open_fee_vault::*;
+pub use close_fee_vault::*;
+pub use auto_renew::*;
+pub use create_coupon::*;
+pub use set_coupon_active::*;
+pub use set_sol_price_feed::*;
+pub use register_college_with_sol::*;
+pub use renew_subscription_with_sol::*;
+pub use withdraw_sol::*;
+pub use update_college_profile::*;
+pub use propose_college_authority::*;
+pub use accept_college_authority::*;
+pub use cancel_college_authority_transfer::*;
+pub use set_college_update_authority::*;
+pub use update_collection::*;
+pub use create_issuer_delegate::*;
+pub use revoke_issuer_delegate::*;
+pub use register_accreditor::*;
+pub use set_accreditor_active::*;
+pub use attest_accreditation::*;
+pub use revoke_accreditation::*;
+pub use expire_accreditation::*;
 
 
 
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::{AcceptedMint, CollegeAccount, MetaverfAccount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct OpenFeeVault<'info> {
+    #[account(mut)]
+    pub college_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    pub mint_usdc: InterfaceAccount<'info, Mint>,//The payment mint or one of the accepted mints
+
+    #[account(
+        seeds = [b"accepted_mint", mint_usdc.key().as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,//Only needed for an accepted mint
+
+    #[account(
+        init,
+        payer = college_authority,
+        seeds = [b"fee_vault", college_account.key().as_ref()],
+        bump,
+        token::mint = mint_usdc,
+        token::authority = college_account,
+        token::token_program = token_program,
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,//Anyone can fund it with a plain token transfer
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OpenFeeVault<'info> {
+    // Opening a vault opts the college into `auto_renew`
+    pub fn open_fee_vault(&mut self) -> Result<()> {
+        let mint = self.mint_usdc.key();
+        require!(
+            mint == self.metaverf_account.payment_mint
+                || self.accepted_mint.as_ref().is_some_and(|accepted| accepted.enabled),
+            CertificateError::InvalidPaymentMint
+        );
+
+        self.college_account.fee_vault_mint = Some(mint);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::{MetaverfAccount, Role, RoleAssignment};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct PauseProtocol<'info> {
+    pub authority: Signer<'info>,//The admin or a pauser
+
+    #[account(
+        seeds = [b"role", Role::Pauser.seed(), authority.key().as_ref()],
+        bump = pauser.bump
+    )]
+    pub pauser: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = metaverf_account.admin == authority.key() || pauser.is_some() @ CertificateError::MissingRole,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+}
+
+impl<'info> PauseProtocol<'info> {
+    // Stays with the admin and pausers even in council mode so issuance can be halted in one transaction
+    pub fn pause_protocol(&mut self) -> Result<()> {
+        self.metaverf_account.paused = true;
+
+        Ok(())
+    }
+
+    pub fn unpause_protocol(&mut self) -> Result<()> {
+        self.metaverf_account.paused = false;
+
+        Ok(())
+    }
+}
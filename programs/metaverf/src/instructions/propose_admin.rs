@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::state::MetaverfAccount;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    pub fn propose_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        // The transfer only takes effect once `new_admin` signs `accept_admin`
+        self.metaverf_account.pending_admin = Some(new_admin);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::CollegeAccount;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct ProposeCollegeAuthority<'info> {
+    pub college_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+}
+
+impl<'info> ProposeCollegeAuthority<'info> {
+    pub fn propose_college_authority(&mut self, new_authority: Pubkey) -> Result<()> {
+        // The transfer only takes effect once `new_authority` signs `accept_college_authority`
+        self.college_account.pending_authority = Some(new_authority);
+
+        Ok(())
+    }
+}
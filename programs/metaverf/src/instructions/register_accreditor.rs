@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{Accreditor, MetaverfAccount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct RegisterAccreditor<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"accreditor", authority.as_ref()],
+        bump,
+        space = 8 + Accreditor::INIT_SPACE,
+    )]
+    pub accreditor: Account<'info, Accreditor>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterAccreditor<'info> {
+    pub fn register_accreditor(&mut self, authority: Pubkey, name: String, bumps: &RegisterAccreditorBumps) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() <= Accreditor::MAX_NAME_LEN,
+            CertificateError::InvalidAccreditor
+        );
+
+        self.accreditor.set_inner(Accreditor {
+            authority,
+            name,
+            active: true,
+            bump: bumps.accreditor,
+        });
+
+        Ok(())
+    }
+}
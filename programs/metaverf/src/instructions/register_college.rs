@@ -6,18 +6,35 @@ use anchor_spl::{
 };
 
 
-use crate::state::{CollegeAccount, MetaverfAccount};
+use crate::state::{AcceptedMint, CollegeAccount, CollegeLookup, CollegeProfile, CollegeProfileArgs, Coupon, MetaverfAccount, Plan};
+use crate::error::CertificateError;
 
 
 #[derive(Accounts)]
-#[instruction(college_id: u16)]
 pub struct RegisterCollege<'info> {
 
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub mint_usdc: InterfaceAccount<'info, Mint>,//The payment mint or one of the accepted mints
 
-    #[account(mut)]
-    pub mint_usdc: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [b"accepted_mint", mint_usdc.key().as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,//Only needed when paying in an accepted mint
+
+    #[account(
+        seeds = [b"plan", plan.id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        constraint = plan.active @ CertificateError::InvalidPlan,
+    )]
+    pub plan: Option<Account<'info, Plan>>,//The plan to subscribe to, `None` for the default annual fee
+
+    #[account(
+        mut,
+        seeds = [b"coupon", coupon.code.as_bytes()],
+        bump = coupon.bump
+    )]
+    pub coupon: Option<Account<'info, Coupon>>,//An optional promo code
 
     #[account(mut)]
     pub college_authority: Signer<'info>,
@@ -25,7 +42,8 @@ pub struct RegisterCollege<'info> {
     #[account(
         mut,
         seeds = [b"protocol"],
-        bump = metaverf_account.verf_bump
+        bump = metaverf_account.verf_bump,
+        constraint = !metaverf_account.paused @ CertificateError::ProtocolPaused,
     )]
     pub metaverf_account: Account<'info, MetaverfAccount>,
 
@@ -39,12 +57,30 @@ pub struct RegisterCollege<'info> {
     #[account(
         init,
         payer = college_authority,
-        seeds = [b"college", college_id.to_le_bytes().as_ref()], //
+        seeds = [b"college", metaverf_account.next_college_id.to_le_bytes().as_ref()],
         bump,
         space = 8 + CollegeAccount::INIT_SPACE
     )]
     pub college_account: Account<'info, CollegeAccount>,
 
+    #[account(
+        init,
+        payer = college_authority,
+        seeds = [b"college_lookup", college_authority.key().as_ref()],
+        bump,
+        space = 8 + CollegeLookup::INIT_SPACE
+    )]
+    pub college_lookup: Account<'info, CollegeLookup>,//One college per authority
+
+    #[account(
+        init,
+        payer = college_authority,
+        seeds = [b"college_profile", college_account.key().as_ref()],
+        bump,
+        space = 8 + CollegeProfile::INIT_SPACE
+    )]
+    pub college_profile: Account<'info, CollegeProfile>,
+
     #[account(
         mut,
         associated_token::mint = mint_usdc,
@@ -58,17 +94,43 @@ pub struct RegisterCollege<'info> {
 }
 
 impl<'info> RegisterCollege<'info> {
-    pub fn register_college(&mut self,college_id:u16,bumps: &RegisterCollegeBumps) -> Result<()> { //, college_id: u16
+    pub fn register_college(&mut self, profile: CollegeProfileArgs, bumps: &RegisterCollegeBumps) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let college_id = self.metaverf_account.assign_college_id()?;
+        let annual_fee = self
+            .metaverf_account
+            .annual_fee_in(&self.mint_usdc.key(), self.accepted_mint.as_deref(), self.plan.as_deref())?;
+        let annual_fee = match self.coupon.as_mut() {
+            Some(coupon) => {
+                let discount = coupon.redeem(college_id, now)?;
+                self.metaverf_account.apply_coupon(
+                    annual_fee,
+                    discount,
+                    &self.mint_usdc.key(),
+                    self.accepted_mint.as_deref(),
+                )?
+            }
+            None => annual_fee,
+        };
+
         // Initialize the college account
-        self.college_account.set_inner(CollegeAccount {
-            id: college_id,
-            authority: self.college_authority.key(),
-            last_payment: Clock::get()?.unix_timestamp,
-            active: true,
-            bump: bumps.college_account,
-            update_authority: self.college_authority.key(),
-            collections: Vec::new(),
+        self.college_account.set_inner(CollegeAccount::new(
+            college_id,
+            self.college_authority.key(),
+            self.plan.as_ref().map(|plan| plan.key()),
+            &self.metaverf_account,
+            now,
+            bumps.college_account,
+        ));
+        self.college_account.record_payment(self.mint_usdc.key(), annual_fee, now)?;
+        self.college_lookup.set_inner(CollegeLookup {
+            college_id,
+            college: self.college_account.key(),
+            bump: bumps.college_lookup,
         });
+        self.college_profile.college = self.college_account.key();
+        self.college_profile.bump = bumps.college_profile;
+        self.college_profile.set(profile)?;
         
         // Transfer annual fee to protocol treasury
         let cpi_accounts = TransferChecked {
@@ -85,13 +147,10 @@ impl<'info> RegisterCollege<'info> {
 
         transfer_checked(
             cpi_ctx,
-            self.metaverf_account.annual_fee,
+            annual_fee,
             self.mint_usdc.decimals,
         )?;
 
-        // Update protocol's college counter
-        self.metaverf_account.uni_no += 1;
-        
         Ok(())
     }
 }
\ No newline at end of file
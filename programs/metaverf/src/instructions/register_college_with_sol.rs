@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{CollegeAccount, CollegeLookup, CollegeProfile, CollegeProfileArgs, Coupon, MetaverfAccount, Plan};
+use crate::oracle::PriceFeed;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct RegisterCollegeWithSol<'info> {
+    #[account(mut)]
+    pub college_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = !metaverf_account.paused @ CertificateError::ProtocolPaused,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(address = metaverf_account.payment_mint @ CertificateError::InvalidPaymentMint)]
+    pub mint_usdc: InterfaceAccount<'info, Mint>,//Only read for its decimals, the fee is set in this mint
+
+    #[account(
+        address = metaverf_account.sol_price_feed @ CertificateError::InvalidPriceFeed,
+        constraint = metaverf_account.sol_price_feed != Pubkey::default() @ CertificateError::InvalidPriceFeed,
+    )]
+    ///CHECK: Pinned by the protocol and parsed by `PriceFeed::load`
+    pub price_feed: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_vault"],
+        bump,
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"plan", plan.id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        constraint = plan.active @ CertificateError::InvalidPlan,
+    )]
+    pub plan: Option<Account<'info, Plan>>,//The plan to subscribe to, `None` for the default annual fee
+
+    #[account(
+        mut,
+        seeds = [b"coupon", coupon.code.as_bytes()],
+        bump = coupon.bump
+    )]
+    pub coupon: Option<Account<'info, Coupon>>,//An optional promo code
+
+    #[account(
+        init,
+        payer = college_authority,
+        seeds = [b"college", metaverf_account.next_college_id.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + CollegeAccount::INIT_SPACE
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        init,
+        payer = college_authority,
+        seeds = [b"college_lookup", college_authority.key().as_ref()],
+        bump,
+        space = 8 + CollegeLookup::INIT_SPACE
+    )]
+    pub college_lookup: Account<'info, CollegeLookup>,//One college per authority
+
+    #[account(
+        init,
+        payer = college_authority,
+        seeds = [b"college_profile", college_account.key().as_ref()],
+        bump,
+        space = 8 + CollegeProfile::INIT_SPACE
+    )]
+    pub college_profile: Account<'info, CollegeProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterCollegeWithSol<'info> {
+    pub fn register_college_with_sol(&mut self, profile: CollegeProfileArgs, bumps: &RegisterCollegeWithSolBumps) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let college_id = self.metaverf_account.assign_college_id()?;
+        let payment_mint = self.metaverf_account.payment_mint;
+        let annual_fee = self
+            .metaverf_account
+            .annual_fee_in(&payment_mint, None, self.plan.as_deref())?;
+        let annual_fee = match self.coupon.as_mut() {
+            Some(coupon) => {
+                let discount = coupon.redeem(college_id, now)?;
+                self.metaverf_account.apply_coupon(annual_fee, discount, &payment_mint, None)?
+            }
+            None => annual_fee,
+        };
+
+        let feed = PriceFeed::load(&self.price_feed.try_borrow_data()?)?;
+        feed.check(
+            now,
+            self.metaverf_account.max_price_age,
+            self.metaverf_account.max_price_confidence_bps,
+        )?;
+        let lamports = feed.usd_to_lamports(annual_fee, self.mint_usdc.decimals)?;
+
+        self.college_account.set_inner(CollegeAccount::new(
+            college_id,
+            self.college_authority.key(),
+            self.plan.as_ref().map(|plan| plan.key()),
+            &self.metaverf_account,
+            now,
+            bumps.college_account,
+        ));
+        self.college_account.record_payment(CollegeAccount::PAID_IN_SOL, lamports, now)?;
+        self.college_lookup.set_inner(CollegeLookup {
+            college_id,
+            college: self.college_account.key(),
+            bump: bumps.college_lookup,
+        });
+        self.college_profile.college = self.college_account.key();
+        self.college_profile.bump = bumps.college_profile;
+        self.college_profile.set(profile)?;
+
+        let cpi_accounts = Transfer {
+            from: self.college_authority.to_account_info(),
+            to: self.sol_vault.to_account_info(),
+        };
+        transfer(CpiContext::new(self.system_program.to_account_info(), cpi_accounts), lamports)?;
+
+        Ok(())
+    }
+}
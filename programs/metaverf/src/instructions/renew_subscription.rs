@@ -6,24 +6,30 @@ use anchor_spl::{
 
 use anchor_spl::token::{transfer_checked, TransferChecked};
 
-use crate::state::{CollegeAccount, MetaverfAccount};
+use crate::state::{AcceptedMint, CollegeAccount, Coupon, DeactivationReason, MetaverfAccount, Plan};
+use crate::error::CertificateError;
 
 
 #[derive(Accounts)]
-#[instruction(college_id: u16)]
+#[instruction(college_id: u64)]
 pub struct RenewSubscription<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
-    #[account(mut)]
-    pub mint_usdc: InterfaceAccount<'info, Mint>,
+    pub mint_usdc: InterfaceAccount<'info, Mint>,//The payment mint or one of the accepted mints
+
+    #[account(
+        seeds = [b"accepted_mint", mint_usdc.key().as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,//Only needed when paying in an accepted mint
 
     #[account(mut)]
-    pub college_authority: Signer<'info>,
+    pub payer: Signer<'info>,//The college authority or any sponsor paying on the college's behalf, refunds on close go to the college authority
 
     #[account(
         mut,
         seeds = [b"protocol"],
-        bump = metaverf_account.verf_bump
+        bump = metaverf_account.verf_bump,
+        constraint = !metaverf_account.paused @ CertificateError::ProtocolPaused,
     )]
     pub metaverf_account: Account<'info, MetaverfAccount>,
 
@@ -36,15 +42,29 @@ pub struct RenewSubscription<'info> {
 
     #[account(
         mut,
-        seeds = [b"college", college_id.to_le_bytes().as_ref()], //, metaverf_account.uni_no.to_le_bytes().as_ref()
+        seeds = [b"college", college_id.to_le_bytes().as_ref()],
         bump = college_account.bump
     )]
     pub college_account: Account<'info, CollegeAccount>,
 
+    #[account(
+        seeds = [b"plan", plan.id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        constraint = college_account.plan == Some(plan.key()) @ CertificateError::InvalidPlan,
+    )]
+    pub plan: Option<Account<'info, Plan>>,//Must be passed when the college is on a plan
+
+    #[account(
+        mut,
+        seeds = [b"coupon", coupon.code.as_bytes()],
+        bump = coupon.bump
+    )]
+    pub coupon: Option<Account<'info, Coupon>>,//An optional promo code
+
     #[account(
         mut,
         associated_token::mint = mint_usdc,
-        associated_token::authority = college_authority
+        associated_token::authority = payer
     )]
     pub payer_token_account: InterfaceAccount<'info, TokenAccount>,
 
@@ -54,16 +74,32 @@ pub struct RenewSubscription<'info> {
 }
 
 impl<'info> RenewSubscription<'info> {
-    pub fn renew_subscription(&mut self) -> Result<()> {
+    pub fn renew_subscription(&mut self, periods: u8) -> Result<()> {
+        self.college_account.require_plan(self.plan.as_deref())?;
+
         let current_time = Clock::get()?.unix_timestamp;
-        let _expiry_time =
-            self.college_account.last_payment + self.metaverf_account.subscription_duration;
+        let annual_fee = self
+            .metaverf_account
+            .annual_fee_in(&self.mint_usdc.key(), self.accepted_mint.as_deref(), self.plan.as_deref())?;
+        let amount = self.metaverf_account.renewal_cost(annual_fee, periods)?;
+        let amount = match self.coupon.as_mut() {
+            Some(coupon) => {
+                let discount = coupon.redeem(self.college_account.college_id, current_time)?;
+                self.metaverf_account.apply_coupon(
+                    amount,
+                    discount,
+                    &self.mint_usdc.key(),
+                    self.accepted_mint.as_deref(),
+                )?
+            }
+            None => amount,
+        };
 
         let cpi_accounts = TransferChecked {
             from: self.payer_token_account.to_account_info(),
             mint: self.mint_usdc.to_account_info(),
             to: self.treasury.to_account_info(),
-            authority: self.college_authority.to_account_info(),
+            authority: self.payer.to_account_info(),
         };
 
         let cpi_program = self.token_program.to_account_info();
@@ -71,12 +107,19 @@ impl<'info> RenewSubscription<'info> {
 
         transfer_checked(
             cpi_ctx,
-            self.metaverf_account.annual_fee,
+            amount,
             self.mint_usdc.decimals,
         )?;
 
-        self.college_account.last_payment = current_time;
-        self.college_account.active = true;
+        self.college_account
+            .record_payment(self.mint_usdc.key(), amount, current_time)?;
+        self.college_account
+            .extend_subscription(&self.metaverf_account, periods, current_time)?;
+
+        // Paying does not lift a moderator's suspension
+        if self.college_account.deactivation_reason != DeactivationReason::Suspended {
+            self.college_account.activate();
+        }
 
         Ok(())
     }
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{CollegeAccount, Coupon, DeactivationReason, MetaverfAccount, Plan};
+use crate::oracle::PriceFeed;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct RenewSubscriptionWithSol<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,//The college authority or any sponsor paying on the college's behalf
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = !metaverf_account.paused @ CertificateError::ProtocolPaused,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(address = metaverf_account.payment_mint @ CertificateError::InvalidPaymentMint)]
+    pub mint_usdc: InterfaceAccount<'info, Mint>,//Only read for its decimals, the fee is set in this mint
+
+    #[account(
+        address = metaverf_account.sol_price_feed @ CertificateError::InvalidPriceFeed,
+        constraint = metaverf_account.sol_price_feed != Pubkey::default() @ CertificateError::InvalidPriceFeed,
+    )]
+    ///CHECK: Pinned by the protocol and parsed by `PriceFeed::load`
+    pub price_feed: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_vault"],
+        bump,
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"college", college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        seeds = [b"plan", plan.id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        constraint = college_account.plan == Some(plan.key()) @ CertificateError::InvalidPlan,
+    )]
+    pub plan: Option<Account<'info, Plan>>,//Must be passed when the college is on a plan
+
+    #[account(
+        mut,
+        seeds = [b"coupon", coupon.code.as_bytes()],
+        bump = coupon.bump
+    )]
+    pub coupon: Option<Account<'info, Coupon>>,//An optional promo code
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RenewSubscriptionWithSol<'info> {
+    pub fn renew_subscription_with_sol(&mut self, periods: u8) -> Result<()> {
+        self.college_account.require_plan(self.plan.as_deref())?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let payment_mint = self.metaverf_account.payment_mint;
+        let annual_fee = self
+            .metaverf_account
+            .annual_fee_in(&payment_mint, None, self.plan.as_deref())?;
+        let amount = self.metaverf_account.renewal_cost(annual_fee, periods)?;
+        let amount = match self.coupon.as_mut() {
+            Some(coupon) => {
+                let discount = coupon.redeem(self.college_account.college_id, now)?;
+                self.metaverf_account.apply_coupon(amount, discount, &payment_mint, None)?
+            }
+            None => amount,
+        };
+
+        let feed = PriceFeed::load(&self.price_feed.try_borrow_data()?)?;
+        feed.check(
+            now,
+            self.metaverf_account.max_price_age,
+            self.metaverf_account.max_price_confidence_bps,
+        )?;
+        let lamports = feed.usd_to_lamports(amount, self.mint_usdc.decimals)?;
+
+        let cpi_accounts = Transfer {
+            from: self.payer.to_account_info(),
+            to: self.sol_vault.to_account_info(),
+        };
+        transfer(CpiContext::new(self.system_program.to_account_info(), cpi_accounts), lamports)?;
+
+        self.college_account
+            .record_payment(CollegeAccount::PAID_IN_SOL, lamports, now)?;
+        self.college_account
+            .extend_subscription(&self.metaverf_account, periods, now)?;
+
+        // Paying does not lift a moderator's suspension
+        if self.college_account.deactivation_reason != DeactivationReason::Suspended {
+            self.college_account.activate();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{Accreditation, AccreditationStatus, Accreditor, CollegeAccount};
+use crate::error::CertificateError;
+use crate::events::AccreditationChanged;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct RevokeAccreditation<'info> {
+    pub accreditor_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"accreditor", accreditor_authority.key().as_ref()],
+        bump = accreditor.bump
+    )]
+    pub accreditor: Account<'info, Accreditor>,//An inactive accreditor may still withdraw what it attested
+
+    #[account(
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"accreditation", accreditor.key().as_ref(), college_account.key().as_ref(), accreditation.programme.as_bytes()],
+        bump = accreditation.bump,
+        constraint = accreditation.status == AccreditationStatus::Accredited @ CertificateError::AccreditationNotActive,
+    )]
+    pub accreditation: Account<'info, Accreditation>,
+}
+
+impl<'info> RevokeAccreditation<'info> {
+    pub fn revoke_accreditation(&mut self) -> Result<()> {
+        self.accreditation.status = AccreditationStatus::Revoked;
+        self.accreditation.revoked_at = Clock::get()?.unix_timestamp;
+
+        emit!(AccreditationChanged {
+            college_id: self.college_account.college_id,
+            accreditor: self.accreditor.key(),
+            programme: self.accreditation.programme.clone(),
+            status: AccreditationStatus::Revoked,
+            expires_at: self.accreditation.expires_at,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::{CollegeAccount, IssuerDelegate};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct RevokeIssuerDelegate<'info> {
+    #[account(mut)]
+    pub college_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"issuer_delegate", college_account.key().as_ref(), issuer_delegate.delegate.as_ref()],
+        bump = issuer_delegate.bump,
+        close = college_authority,
+    )]
+    pub issuer_delegate: Account<'info, IssuerDelegate>,
+}
+
+impl<'info> RevokeIssuerDelegate<'info> {
+    pub fn revoke_issuer_delegate(&mut self) -> Result<()> {
+        // Closing the delegate is the revocation, `close = college_authority` does the work
+        Ok(())
+    }
+}
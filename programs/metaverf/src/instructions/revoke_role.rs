@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::{MetaverfAccount, RoleAssignment};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"role", role_assignment.role.seed(), role_assignment.holder.as_ref()],
+        bump = role_assignment.bump,
+        close = admin,
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+}
+
+impl<'info> RevokeRole<'info> {
+    pub fn revoke_role(&mut self) -> Result<()> {
+        // Closing the assignment is the revocation, `close = admin` does the work
+        Ok(())
+    }
+}
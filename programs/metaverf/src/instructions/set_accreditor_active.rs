@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::{Accreditor, MetaverfAccount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct SetAccreditorActive<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"accreditor", accreditor.authority.as_ref()],
+        bump = accreditor.bump,
+    )]
+    pub accreditor: Account<'info, Accreditor>,
+}
+
+impl<'info> SetAccreditorActive<'info> {
+    // Deactivating keeps the account, so verifiers can still see who attested what. Its attestations
+    // are then revoked one by one with `expire_accreditation`.
+    pub fn set_accreditor_active(&mut self, active: bool) -> Result<()> {
+        self.accreditor.active = active;
+
+        Ok(())
+    }
+}
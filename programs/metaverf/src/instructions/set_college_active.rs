@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{CollegeAccount, DeactivationReason, MetaverfAccount, Role, RoleAssignment};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct SetCollegeActive<'info> {
+    pub authority: Signer<'info>,//The admin or a college moderator
+
+    #[account(
+        seeds = [b"role", Role::CollegeModerator.seed(), authority.key().as_ref()],
+        bump = college_moderator.bump
+    )]
+    pub college_moderator: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = metaverf_account.admin == authority.key() || college_moderator.is_some() @ CertificateError::MissingRole,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"college", college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+}
+
+impl<'info> SetCollegeActive<'info> {
+    // Suspends a college from issuing, or reinstates it
+    pub fn set_college_active(&mut self, active: bool) -> Result<()> {
+        if active {
+            self.college_account.activate();
+        } else {
+            let now = Clock::get()?.unix_timestamp;
+            self.college_account.deactivate(DeactivationReason::Suspended, now);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::CollegeAccount;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct SetCollegeUpdateAuthority<'info> {
+    pub college_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+}
+
+impl<'info> SetCollegeUpdateAuthority<'info> {
+    // The college authority can always take metadata control back, so this is a single step
+    pub fn set_college_update_authority(&mut self, update_authority: Pubkey) -> Result<()> {
+        self.college_account.update_authority = update_authority;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::MetaverfAccount;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct SetCouncil<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+        constraint = !metaverf_account.council_enabled() @ CertificateError::CouncilModeActive,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+}
+
+impl<'info> SetCouncil<'info> {
+    // Turns council mode on. Once it is on, the council itself changes its members through a proposal
+    pub fn set_council(&mut self, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        require!(threshold > 0, CertificateError::InvalidCouncil);
+
+        self.metaverf_account.set_council(members, threshold)
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::{Coupon, MetaverfAccount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct SetCouponActive<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"coupon", coupon.code.as_bytes()],
+        bump = coupon.bump
+    )]
+    pub coupon: Account<'info, Coupon>,
+}
+
+impl<'info> SetCouponActive<'info> {
+    // The admin can always withdraw a coupon, but only a council proposal can enable one in council mode
+    pub fn set_coupon_active(&mut self, active: bool) -> Result<()> {
+        require!(
+            !active || !self.metaverf_account.council_enabled(),
+            CertificateError::CouncilModeActive
+        );
+
+        self.coupon.active = active;
+
+        Ok(())
+    }
+}
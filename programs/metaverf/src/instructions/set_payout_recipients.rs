@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::state::{MetaverfAccount, PayoutRecipient};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct SetPayoutRecipients<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+        constraint = !metaverf_account.council_enabled() @ CertificateError::CouncilModeActive,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+}
+
+impl<'info> SetPayoutRecipients<'info> {
+    pub fn set_payout_recipients(&mut self, recipients: Vec<PayoutRecipient>) -> Result<()> {
+        self.metaverf_account.set_payout_recipients(recipients)
+    }
+}
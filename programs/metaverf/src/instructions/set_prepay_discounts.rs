@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::state::{MetaverfAccount, PrepayDiscount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct SetPrepayDiscounts<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+        constraint = !metaverf_account.council_enabled() @ CertificateError::CouncilModeActive,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+}
+
+impl<'info> SetPrepayDiscounts<'info> {
+    pub fn set_prepay_discounts(&mut self, discounts: Vec<PrepayDiscount>) -> Result<()> {
+        self.metaverf_account.set_prepay_discounts(discounts)
+    }
+}
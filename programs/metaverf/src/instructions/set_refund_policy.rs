@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::state::MetaverfAccount;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct SetRefundPolicy<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+        constraint = !metaverf_account.council_enabled() @ CertificateError::CouncilModeActive,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+}
+
+impl<'info> SetRefundPolicy<'info> {
+    pub fn set_refund_policy(&mut self, refund_bps: u16) -> Result<()> {
+        self.metaverf_account.set_refund_policy(refund_bps)
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::state::MetaverfAccount;
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct SetSolPriceFeed<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+        constraint = !metaverf_account.council_enabled() @ CertificateError::CouncilModeActive,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+}
+
+impl<'info> SetSolPriceFeed<'info> {
+    pub fn set_sol_price_feed(&mut self, feed: Pubkey, max_age: i64, max_confidence_bps: u16) -> Result<()> {
+        self.metaverf_account.set_sol_price_feed(feed, max_age, max_confidence_bps)
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::{AcceptedMint, MetaverfAccount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct UpdateAcceptedMint<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+        constraint = !metaverf_account.council_enabled() @ CertificateError::CouncilModeActive,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"accepted_mint", accepted_mint.mint.as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+}
+
+impl<'info> UpdateAcceptedMint<'info> {
+    pub fn update_accepted_mint(&mut self, annual_fee: Option<u64>, enabled: Option<bool>) -> Result<()> {
+        let effective_at = Clock::get()?.unix_timestamp + self.metaverf_account.parameter_delay;
+        self.accepted_mint.update(annual_fee, enabled, effective_at);
+
+        Ok(())
+    }
+}
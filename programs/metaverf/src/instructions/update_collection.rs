@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    accounts::BaseCollectionV1,
+    instructions::UpdateCollectionV1CpiBuilder,
+    ID as MPL_CORE_ID,
+};
+
+use crate::state::{CollegeAccount, MetaverfAccount};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+#[instruction(college_id: u64)]
+pub struct UpdateCollection<'info> {
+    #[account(mut)]
+    pub update_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.update_authority == update_authority.key() @ CertificateError::NotAuthorized,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = !metaverf_account.paused @ CertificateError::ProtocolPaused,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        constraint = college_account.collections.iter().any(|c| c.collection == collection.key()) @ CertificateError::CollectionNotFound,
+        constraint = collection.update_authority == college_account.key() @ CertificateError::NotAuthorized,
+    )]
+    ///CHECK: UncheckedAccount will be checked by mpl
+    pub collection: Account<'info, BaseCollectionV1>,//Only collections owned by the college PDA, older ones stay with their creator
+
+    #[account(address = MPL_CORE_ID)]
+    ///CHECK: UncheckedAccount will be checked by mpl
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateCollectionArgs {
+    pub name: Option<String>,
+    pub uri: Option<String>,
+}
+
+impl<'info> UpdateCollection<'info> {
+    pub fn update_collection(&mut self, args: UpdateCollectionArgs) -> Result<()> {
+        require!(
+            args.name.as_ref().is_none_or(|name| name.len() <= 64)
+                && args.uri.as_ref().is_none_or(|uri| uri.len() <= 128),
+            CertificateError::CollectionMetadataTooLong
+        );
+
+        let id = self.college_account.college_id.to_le_bytes();
+        let seeds = &[b"college".as_ref(), id.as_ref(), &[self.college_account.bump]];
+        let signer = &[&seeds[..]];
+
+        let mpl_core_program = self.mpl_core_program.to_account_info();
+        let collection = self.collection.to_account_info();
+        let payer = self.update_authority.to_account_info();
+        let college_account = self.college_account.to_account_info();
+        let system_program = self.system_program.to_account_info();
+
+        let mut update = UpdateCollectionV1CpiBuilder::new(&mpl_core_program);
+        update
+            .collection(&collection)
+            .payer(&payer)
+            .authority(Some(&college_account))
+            .system_program(&system_program);
+        if let Some(name) = args.name.clone() {
+            update.new_name(name);
+        }
+        if let Some(uri) = args.uri.clone() {
+            update.new_uri(uri);
+        }
+        update.invoke_signed(signer)?;
+
+        // Keep the college's copy of the metadata in step with the collection
+        let collection_key = self.collection.key();
+        if let Some(info) = self.college_account.collections.iter_mut().find(|c| c.collection == collection_key) {
+            if let Some(name) = args.name {
+                info.name = name;
+            }
+            if let Some(uri) = args.uri {
+                info.uri = uri;
+            }
+        }
+
+        Ok(())
+    }
+}
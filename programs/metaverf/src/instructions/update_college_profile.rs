@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::{CollegeAccount, CollegeProfile, CollegeProfileArgs};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct UpdateCollegeProfile<'info> {
+    #[account(mut)]
+    pub college_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"college", college_account.college_id.to_le_bytes().as_ref()],
+        bump = college_account.bump,
+        constraint = college_account.authority == college_authority.key() @ CertificateError::NotAuthorized,
+    )]
+    pub college_account: Account<'info, CollegeAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"college_profile", college_account.key().as_ref()],
+        bump = college_profile.bump
+    )]
+    pub college_profile: Account<'info, CollegeProfile>,
+}
+
+impl<'info> UpdateCollegeProfile<'info> {
+    pub fn update_college_profile(&mut self, args: CollegeProfileArgs) -> Result<()> {
+        self.college_profile.set(args)
+    }
+}
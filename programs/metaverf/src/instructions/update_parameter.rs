@@ -1,17 +1,33 @@
 use anchor_lang::prelude::*;
-use crate::state::MetaverfAccount;
+use crate::state::{MetaverfAccount, PendingParameters, Role, RoleAssignment};
+use crate::error::CertificateError;
 
 #[derive(Accounts)]
 pub struct UpdateParameter<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,//The admin or a fee manager
+
+    #[account(
+        seeds = [b"role", Role::FeeManager.seed(), authority.key().as_ref()],
+        bump = fee_manager.bump
+    )]
+    pub fee_manager: Option<Account<'info, RoleAssignment>>,
 
     #[account(
         mut,
         seeds = [b"protocol"],
-        bump = metaverf_account.verf_bump
+        bump = metaverf_account.verf_bump,
+        constraint = metaverf_account.admin == authority.key() || fee_manager.is_some() @ CertificateError::MissingRole,
+        constraint = !metaverf_account.council_enabled() @ CertificateError::CouncilModeActive,
     )]
     pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_parameters"],
+        bump = pending_parameters.bump
+    )]
+    pub pending_parameters: Account<'info, PendingParameters>,
 }
 
 impl<'info> UpdateParameter<'info> {
@@ -19,15 +35,13 @@ impl<'info> UpdateParameter<'info> {
         &mut self,
         annual_fee: Option<u64>,
         subscription_duration: Option<i64>,
+        grace_period: Option<i64>,
+        certificate_fee: Option<u64>,
     ) -> Result<()> {
-        if let Some(fee) = annual_fee {
-            self.metaverf_account.annual_fee = fee;
-        }
-
-        if let Some(duration) = subscription_duration {
-            self.metaverf_account.subscription_duration = duration;
-        } 
+        require!(grace_period.is_none_or(|grace| grace >= 0), CertificateError::InvalidGracePeriod);
 
-        Ok(())
+        // Changes are only queued here, `apply_parameters` activates them after the delay
+        let effective_at = Clock::get()?.unix_timestamp + self.metaverf_account.parameter_delay;
+        self.pending_parameters.queue(annual_fee, subscription_duration, grace_period, certificate_fee, effective_at)
     }
 }
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{CollegeAccount, MetaverfAccount, Plan};
+use crate::error::CertificateError;
+
+#[derive(Accounts)]
+pub struct UpdatePlan<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        has_one = admin @ CertificateError::UnauthorizedAdmin,
+        constraint = !metaverf_account.council_enabled() @ CertificateError::CouncilModeActive,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"plan", plan.id.to_le_bytes().as_ref()],
+        bump = plan.bump
+    )]
+    pub plan: Account<'info, Plan>,
+}
+
+impl<'info> UpdatePlan<'info> {
+    pub fn update_plan(
+        &mut self,
+        price: Option<u64>,
+        max_collections: Option<u8>,
+        yearly_certificate_quota: Option<u32>,
+        active: Option<bool>,
+    ) -> Result<()> {
+        require!(
+            max_collections.is_none_or(|max| max as usize <= CollegeAccount::MAX_COLLECTIONS),
+            CertificateError::InvalidPlan
+        );
+
+        let effective_at = Clock::get()?.unix_timestamp + self.metaverf_account.parameter_delay;
+        self.plan.update(price, max_collections, yearly_certificate_quota, active, effective_at);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
-use crate::state::MetaverfAccount;
+use crate::state::{AcceptedMint, MetaverfAccount, Role, RoleAssignment};
+use crate::error::CertificateError;
 use anchor_lang::prelude::*;
 use anchor_spl::token::{transfer_checked, TransferChecked};
 use anchor_spl::{
@@ -9,12 +10,31 @@ use anchor_spl::{
 #[derive(Accounts)]
 pub struct WithdrawFees<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,//The admin or a treasurer
+
+    #[account(
+        seeds = [b"role", Role::Treasurer.seed(), authority.key().as_ref()],
+        bump = treasurer.bump
+    )]
+    pub treasurer: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        constraint = mint_usdc.key() == metaverf_account.payment_mint || accepted_mint.is_some() @ CertificateError::InvalidPaymentMint,
+    )]
     pub mint_usdc: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"accepted_mint", mint_usdc.key().as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,//Only needed when withdrawing an accepted mint
+
     #[account(
         mut,
         seeds = [b"protocol"],
-        bump = metaverf_account.verf_bump
+        bump = metaverf_account.verf_bump,
+        constraint = metaverf_account.admin == authority.key() || treasurer.is_some() @ CertificateError::MissingRole,
+        constraint = !metaverf_account.council_enabled() @ CertificateError::CouncilModeActive,
     )]
     pub metaverf_account: Account<'info, MetaverfAccount>,
 
@@ -28,9 +48,10 @@ pub struct WithdrawFees<'info> {
     #[account(
         mut,
         associated_token::mint = mint_usdc,
-        associated_token::authority = admin
+        associated_token::authority = authority,
+        constraint = metaverf_account.can_withdraw_to(&authority_token_account.owner) @ CertificateError::ProtocolPaused,
     )]
-    pub admin_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -49,7 +70,7 @@ impl<'info> WithdrawFees<'info> {
         let cpi_accounts = TransferChecked {
             from: self.treasury.to_account_info(),
             mint: self.mint_usdc.to_account_info(),
-            to: self.admin_token_account.to_account_info(),
+            to: self.authority_token_account.to_account_info(),
             authority: self.metaverf_account.to_account_info(),
         };
 
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{MetaverfAccount, Role, RoleAssignment};
+use crate::error::CertificateError;
+use crate::utils::transfer_from_sol_vault;
+
+#[derive(Accounts)]
+pub struct WithdrawSol<'info> {
+    #[account(
+        mut,
+        constraint = metaverf_account.can_withdraw_to(&authority.key()) @ CertificateError::ProtocolPaused,
+    )]
+    pub authority: Signer<'info>,//The admin or a treasurer, receives the lamports
+
+    #[account(
+        seeds = [b"role", Role::Treasurer.seed(), authority.key().as_ref()],
+        bump = treasurer.bump
+    )]
+    pub treasurer: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        seeds = [b"protocol"],
+        bump = metaverf_account.verf_bump,
+        constraint = metaverf_account.admin == authority.key() || treasurer.is_some() @ CertificateError::MissingRole,
+        constraint = !metaverf_account.council_enabled() @ CertificateError::CouncilModeActive,
+    )]
+    pub metaverf_account: Account<'info, MetaverfAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_vault"],
+        bump,
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawSol<'info> {
+    pub fn withdraw_sol(&mut self, amount: u64) -> Result<()> {
+        transfer_from_sol_vault(
+            &self.sol_vault.to_account_info(),
+            &self.authority.to_account_info(),
+            &self.system_program.to_account_info(),
+            amount,
+        )
+    }
+}
@@ -2,8 +2,11 @@
 use anchor_lang::prelude::*;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod oracle;
 pub mod state;
+pub mod utils;
 
 pub use constants::*;
 pub use instructions::*;
@@ -27,31 +30,265 @@ declare_id!("C5wcse5xEf1XNtivNWozSM81ebdcFx7gYwiJUS5XUKp2");
 pub mod metaverf {
     use super::*;
 
-    pub fn initialize(ctx: Context<InitializeProtocol>, annual_fee: u64, subscription_duration: i64) -> Result<()> {
-        ctx.accounts.initialize_protocol(annual_fee, subscription_duration, &ctx.bumps)
+    pub fn initialize(ctx: Context<InitializeProtocol>, annual_fee: u64, subscription_duration: i64, parameter_delay: i64, grace_period: i64) -> Result<()> {
+        ctx.accounts.initialize_protocol(annual_fee, subscription_duration, parameter_delay, grace_period, &ctx.bumps)
     }
 
-    pub fn register_college(ctx: Context<RegisterCollege>,college_id: u16) -> Result<()> {
-        ctx.accounts.register_college(college_id,&ctx.bumps)
+    pub fn register_college(ctx: Context<RegisterCollege>, profile: CollegeProfileArgs) -> Result<()> {
+        ctx.accounts.register_college(profile, &ctx.bumps)
     }
 
-    pub fn renew_subscription(ctx: Context<RenewSubscription>,_college_id: u16) -> Result<()> {
-        ctx.accounts.renew_subscription()
+    pub fn renew_subscription(ctx: Context<RenewSubscription>,_college_id: u64, periods: u8) -> Result<()> {
+        ctx.accounts.renew_subscription(periods)
     }
 
-    pub fn update_parameters(ctx: Context<UpdateParameter>, annual_fee: Option<u64>, subscription_duration: Option<i64>) -> Result<()> {
-        ctx.accounts.update_parameters(annual_fee, subscription_duration)
+    pub fn update_parameters(
+        ctx: Context<UpdateParameter>,
+        annual_fee: Option<u64>,
+        subscription_duration: Option<i64>,
+        grace_period: Option<i64>,
+        certificate_fee: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts.update_parameters(annual_fee, subscription_duration, grace_period, certificate_fee)
+    }
+
+    pub fn apply_parameters(ctx: Context<ApplyParameters>) -> Result<()> {
+        ctx.accounts.apply_parameters()
     }
 
     pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
         ctx.accounts.withdraw_fees(amount)
     }
 
-    pub fn add_collection(ctx: Context<AddCollection>,_college_id: u16,args: AddCollectionArgs) -> Result<()> {
+    pub fn add_collection(ctx: Context<AddCollection>,_college_id: u64,args: AddCollectionArgs) -> Result<()> {
         ctx.accounts.add_collection(args)
     }
 
-    pub fn mint_certificate(ctx: Context<MintCertificate>,_college_id: u16, args: CertificateArgs) -> Result<()> {
+    pub fn mint_certificate(ctx: Context<MintCertificate>,_college_id: u64, args: CertificateArgs) -> Result<()> {
         ctx.accounts.mint_certificate(args)
     }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.propose_admin(new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        ctx.accounts.accept_admin()
+    }
+
+    pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+        ctx.accounts.cancel_admin_transfer()
+    }
+
+    pub fn set_council(ctx: Context<SetCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        ctx.accounts.set_council(members, threshold)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        ctx.accounts.create_proposal(action, &ctx.bumps)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        ctx.accounts.approve_proposal()
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        ctx.accounts.execute_proposal()
+    }
+
+    pub fn execute_withdraw_proposal(ctx: Context<ExecuteWithdrawProposal>) -> Result<()> {
+        ctx.accounts.execute_withdraw_proposal()
+    }
+
+    pub fn pause_protocol(ctx: Context<PauseProtocol>) -> Result<()> {
+        ctx.accounts.pause_protocol()
+    }
+
+    pub fn unpause_protocol(ctx: Context<PauseProtocol>) -> Result<()> {
+        ctx.accounts.unpause_protocol()
+    }
+
+    pub fn add_accepted_mint(ctx: Context<AddAcceptedMint>, annual_fee: u64) -> Result<()> {
+        ctx.accounts.add_accepted_mint(annual_fee, &ctx.bumps)
+    }
+
+    pub fn update_accepted_mint(ctx: Context<UpdateAcceptedMint>, annual_fee: Option<u64>, enabled: Option<bool>) -> Result<()> {
+        ctx.accounts.update_accepted_mint(annual_fee, enabled)
+    }
+
+    pub fn apply_accepted_mint_fee(ctx: Context<ApplyAcceptedMintFee>) -> Result<()> {
+        ctx.accounts.apply_accepted_mint_fee()
+    }
+
+    pub fn migrate_protocol(ctx: Context<MigrateProtocol>) -> Result<()> {
+        ctx.accounts.migrate_protocol(&ctx.bumps)
+    }
+
+    pub fn migrate_college(ctx: Context<MigrateCollege>, _college_id: u64) -> Result<()> {
+        ctx.accounts.migrate_college()
+    }
+
+    pub fn migrate_legacy_college(ctx: Context<MigrateLegacyCollege>, _legacy_id: u16) -> Result<()> {
+        ctx.accounts.migrate_legacy_college(&ctx.bumps)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role, holder: Pubkey) -> Result<()> {
+        ctx.accounts.grant_role(role, holder, &ctx.bumps)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        ctx.accounts.revoke_role()
+    }
+
+    pub fn set_college_active(ctx: Context<SetCollegeActive>, _college_id: u64, active: bool) -> Result<()> {
+        ctx.accounts.set_college_active(active)
+    }
+
+    pub fn set_payout_recipients(ctx: Context<SetPayoutRecipients>, recipients: Vec<PayoutRecipient>) -> Result<()> {
+        ctx.accounts.set_payout_recipients(recipients)
+    }
+
+    pub fn distribute_fees<'info>(ctx: Context<'_, '_, 'info, 'info, DistributeFees<'info>>) -> Result<()> {
+        ctx.accounts.distribute_fees(ctx.remaining_accounts)
+    }
+
+    pub fn expire_college(ctx: Context<ExpireCollege>, _college_id: u64) -> Result<()> {
+        ctx.accounts.expire_college()
+    }
+
+    pub fn set_prepay_discounts(ctx: Context<SetPrepayDiscounts>, discounts: Vec<PrepayDiscount>) -> Result<()> {
+        ctx.accounts.set_prepay_discounts(discounts)
+    }
+
+    pub fn create_plan(
+        ctx: Context<CreatePlan>,
+        plan_id: u8,
+        name: String,
+        price: u64,
+        max_collections: u8,
+        yearly_certificate_quota: u32,
+    ) -> Result<()> {
+        ctx.accounts.create_plan(plan_id, name, price, max_collections, yearly_certificate_quota, &ctx.bumps)
+    }
+
+    pub fn update_plan(
+        ctx: Context<UpdatePlan>,
+        price: Option<u64>,
+        max_collections: Option<u8>,
+        yearly_certificate_quota: Option<u32>,
+        active: Option<bool>,
+    ) -> Result<()> {
+        ctx.accounts.update_plan(price, max_collections, yearly_certificate_quota, active)
+    }
+
+    pub fn apply_plan_price(ctx: Context<ApplyPlanPrice>) -> Result<()> {
+        ctx.accounts.apply_plan_price()
+    }
+
+    pub fn change_plan(ctx: Context<ChangePlan>) -> Result<()> {
+        ctx.accounts.change_plan()
+    }
+
+    pub fn set_refund_policy(ctx: Context<SetRefundPolicy>, refund_bps: u16) -> Result<()> {
+        ctx.accounts.set_refund_policy(refund_bps)
+    }
+
+    pub fn close_college<'info>(ctx: Context<'_, '_, 'info, 'info, CloseCollege<'info>>) -> Result<()> {
+        ctx.accounts.close_college(ctx.remaining_accounts, &ctx.bumps)
+    }
+
+    pub fn open_fee_vault(ctx: Context<OpenFeeVault>) -> Result<()> {
+        ctx.accounts.open_fee_vault()
+    }
+
+    pub fn close_fee_vault(ctx: Context<CloseFeeVault>) -> Result<()> {
+        ctx.accounts.close_fee_vault()
+    }
+
+    pub fn auto_renew(ctx: Context<AutoRenew>) -> Result<()> {
+        ctx.accounts.auto_renew()
+    }
+
+    pub fn create_coupon(
+        ctx: Context<CreateCoupon>,
+        code: String,
+        discount: CouponDiscount,
+        max_uses: u32,
+        expires_at: i64,
+        allowed_colleges: Vec<u64>,
+    ) -> Result<()> {
+        ctx.accounts.create_coupon(code, discount, max_uses, expires_at, allowed_colleges, &ctx.bumps)
+    }
+
+    pub fn set_coupon_active(ctx: Context<SetCouponActive>, active: bool) -> Result<()> {
+        ctx.accounts.set_coupon_active(active)
+    }
+
+    pub fn set_sol_price_feed(ctx: Context<SetSolPriceFeed>, feed: Pubkey, max_age: i64, max_confidence_bps: u16) -> Result<()> {
+        ctx.accounts.set_sol_price_feed(feed, max_age, max_confidence_bps)
+    }
+
+    pub fn register_college_with_sol(ctx: Context<RegisterCollegeWithSol>, profile: CollegeProfileArgs) -> Result<()> {
+        ctx.accounts.register_college_with_sol(profile, &ctx.bumps)
+    }
+
+    pub fn renew_subscription_with_sol(ctx: Context<RenewSubscriptionWithSol>, _college_id: u64, periods: u8) -> Result<()> {
+        ctx.accounts.renew_subscription_with_sol(periods)
+    }
+
+    pub fn withdraw_sol(ctx: Context<WithdrawSol>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_sol(amount)
+    }
+
+    pub fn update_college_profile(ctx: Context<UpdateCollegeProfile>, args: CollegeProfileArgs) -> Result<()> {
+        ctx.accounts.update_college_profile(args)
+    }
+
+    pub fn propose_college_authority(ctx: Context<ProposeCollegeAuthority>, _college_id: u64, new_authority: Pubkey) -> Result<()> {
+        ctx.accounts.propose_college_authority(new_authority)
+    }
+
+    pub fn accept_college_authority(ctx: Context<AcceptCollegeAuthority>, _college_id: u64) -> Result<()> {
+        ctx.accounts.accept_college_authority(&ctx.bumps)
+    }
+
+    pub fn cancel_college_authority_transfer(ctx: Context<CancelCollegeAuthorityTransfer>, _college_id: u64) -> Result<()> {
+        ctx.accounts.cancel_college_authority_transfer()
+    }
+
+    pub fn set_college_update_authority(ctx: Context<SetCollegeUpdateAuthority>, _college_id: u64, update_authority: Pubkey) -> Result<()> {
+        ctx.accounts.set_college_update_authority(update_authority)
+    }
+
+    pub fn update_collection(ctx: Context<UpdateCollection>, _college_id: u64, args: UpdateCollectionArgs) -> Result<()> {
+        ctx.accounts.update_collection(args)
+    }
+
+    pub fn create_issuer_delegate(ctx: Context<CreateIssuerDelegate>, _college_id: u64, args: IssuerDelegateArgs) -> Result<()> {
+        ctx.accounts.create_issuer_delegate(args, &ctx.bumps)
+    }
+
+    pub fn revoke_issuer_delegate(ctx: Context<RevokeIssuerDelegate>, _college_id: u64) -> Result<()> {
+        ctx.accounts.revoke_issuer_delegate()
+    }
+
+    pub fn register_accreditor(ctx: Context<RegisterAccreditor>, authority: Pubkey, name: String) -> Result<()> {
+        ctx.accounts.register_accreditor(authority, name, &ctx.bumps)
+    }
+
+    pub fn set_accreditor_active(ctx: Context<SetAccreditorActive>, active: bool) -> Result<()> {
+        ctx.accounts.set_accreditor_active(active)
+    }
+
+    pub fn attest_accreditation(ctx: Context<AttestAccreditation>, _college_id: u64, programme: String, expires_at: i64) -> Result<()> {
+        ctx.accounts.attest_accreditation(programme, expires_at, &ctx.bumps)
+    }
+
+    pub fn revoke_accreditation(ctx: Context<RevokeAccreditation>, _college_id: u64) -> Result<()> {
+        ctx.accounts.revoke_accreditation()
+    }
+
+    pub fn expire_accreditation(ctx: Context<ExpireAccreditation>, _college_id: u64) -> Result<()> {
+        ctx.accounts.expire_accreditation()
+    }
 }
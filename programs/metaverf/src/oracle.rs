@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::error::CertificateError;
+
+// Reads the aggregate price from a Pyth v2 (legacy) price account by offset, so the
+// program needs no oracle SDK and tests can load a hand-written account
+const MAGIC: u32 = 0xa1b2_c3d4;
+const ACCOUNT_TYPE_PRICE: u32 = 3;
+const STATUS_TRADING: u32 = 1;
+
+const MAGIC_OFFSET: usize = 0;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPONENT_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGG_PRICE_OFFSET: usize = 208;
+const AGG_CONF_OFFSET: usize = 216;
+const AGG_STATUS_OFFSET: usize = 224;
+const MIN_LEN: usize = 240;
+
+pub struct PriceFeed {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+fn read<const N: usize>(data: &[u8], offset: usize) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    bytes.copy_from_slice(&data[offset..offset + N]);
+    bytes
+}
+
+impl PriceFeed {
+    pub fn load(data: &[u8]) -> Result<Self> {
+        require!(data.len() >= MIN_LEN, CertificateError::InvalidPriceFeed);
+        require!(
+            u32::from_le_bytes(read(data, MAGIC_OFFSET)) == MAGIC
+                && u32::from_le_bytes(read(data, ACCOUNT_TYPE_OFFSET)) == ACCOUNT_TYPE_PRICE,
+            CertificateError::InvalidPriceFeed
+        );
+        require!(
+            u32::from_le_bytes(read(data, AGG_STATUS_OFFSET)) == STATUS_TRADING,
+            CertificateError::InvalidPriceFeed
+        );
+
+        Ok(Self {
+            price: i64::from_le_bytes(read(data, AGG_PRICE_OFFSET)),
+            conf: u64::from_le_bytes(read(data, AGG_CONF_OFFSET)),
+            expo: i32::from_le_bytes(read(data, EXPONENT_OFFSET)),
+            publish_time: i64::from_le_bytes(read(data, TIMESTAMP_OFFSET)),
+        })
+    }
+
+    // Rejects prices older than `max_age` seconds or less certain than `max_conf_bps` of the price
+    pub fn check(&self, now: i64, max_age: i64, max_conf_bps: u16) -> Result<()> {
+        require!(self.price > 0, CertificateError::InvalidPriceFeed);
+        require!(now.saturating_sub(self.publish_time) <= max_age, CertificateError::StalePrice);
+        require!(
+            self.conf as u128 * 10_000 <= self.price as u128 * max_conf_bps as u128,
+            CertificateError::PriceTooUncertain
+        );
+
+        Ok(())
+    }
+
+    // Lamports worth `amount` base units of a USD stablecoin with `decimals` decimals
+    pub fn usd_to_lamports(&self, amount: u64, decimals: u8) -> Result<u64> {
+        // lamports = amount * 10^(9 - decimals - expo) / price
+        let price = u128::try_from(self.price)
+            .ok()
+            .filter(|price| *price > 0)
+            .ok_or(CertificateError::InvalidPriceFeed)?;
+        let exponent = 9i32
+            .checked_sub(decimals as i32)
+            .and_then(|exponent| exponent.checked_sub(self.expo))
+            .ok_or(CertificateError::MathOverflow)?;
+        let scale = 10u128
+            .checked_pow(exponent.unsigned_abs())
+            .ok_or(CertificateError::MathOverflow)?;
+
+        let lamports = if exponent >= 0 {
+            (amount as u128)
+                .checked_mul(scale)
+                .ok_or(CertificateError::MathOverflow)?
+                / price
+        } else {
+            amount as u128 / price.checked_mul(scale).ok_or(CertificateError::MathOverflow)?
+        };
+
+        u64::try_from(lamports).map_err(|_| error!(CertificateError::MathOverflow))
+    }
+}
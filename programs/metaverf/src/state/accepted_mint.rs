@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+// A stablecoin accepted for fees besides the protocol's `payment_mint`, priced on its own
+#[account]
+#[derive(InitSpace)]
+pub struct AcceptedMint {
+    pub mint: Pubkey,
+    pub annual_fee: u64,//The annual fee charged in this mint's base units
+    pub pending_annual_fee: Option<u64>,//A queued fee change, activated by `apply_accepted_mint_fee`
+    pub fee_effective_at: i64,//From when the queued fee may be applied
+    pub enabled: bool,//Disabled mints can still be withdrawn but no longer pay fees
+    pub bump: u8,
+}
+
+impl AcceptedMint {
+    // Enabling or disabling is immediate, fee changes wait for the protocol's parameter delay
+    pub fn update(&mut self, annual_fee: Option<u64>, enabled: Option<bool>, effective_at: i64) {
+        if let Some(fee) = annual_fee {
+            self.pending_annual_fee = Some(fee);
+            self.fee_effective_at = effective_at;
+        }
+
+        if let Some(enabled) = enabled {
+            self.enabled = enabled;
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::error::CertificateError;
+use crate::state::Accreditor;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AccreditationStatus {
+    Accredited,
+    Revoked,//Withdrawn by the accreditor, or marked by `expire_accreditation` once the accreditor was deactivated
+    Expired,//Marked by `expire_accreditation` once `expires_at` passed
+}
+
+// One accreditor's attestation that a college's programme is accredited
+#[account]
+#[derive(InitSpace)]
+pub struct Accreditation {
+    pub accreditor: Pubkey,//The `Accreditor` account that attested
+    pub college: Pubkey,//The college's `CollegeAccount`
+    #[max_len(32)]
+    pub programme: String,//Part of the PDA seed, so at most 32 bytes
+    pub status: AccreditationStatus,
+    pub attested_at: i64,
+    pub expires_at: i64,//0 for never
+    pub revoked_at: i64,//0 unless revoked
+    pub bump: u8,
+}
+
+impl Accreditation {
+    pub const MAX_PROGRAMME_LEN: usize = 32;
+
+    pub fn validate_programme(programme: &str) -> Result<()> {
+        require!(
+            !programme.is_empty() && programme.len() <= Self::MAX_PROGRAMME_LEN,
+            CertificateError::InvalidProgramme
+        );
+
+        Ok(())
+    }
+
+    // An `Accredited` status goes stale once `expires_at` passes or the admin deactivates the
+    // accreditor, until `expire_accreditation` marks it
+    pub fn is_valid(&self, accreditor: &Account<Accreditor>, now: i64) -> bool {
+        accreditor.key() == self.accreditor
+            && accreditor.active
+            && self.status == AccreditationStatus::Accredited
+            && !self.is_lapsed(now)
+    }
+
+    pub fn is_lapsed(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+// An accreditation body registered by the protocol admin, its authority signs attestations
+#[account]
+#[derive(InitSpace)]
+pub struct Accreditor {
+    pub authority: Pubkey,
+    #[max_len(64)]
+    pub name: String,//E.g. a national accreditation body
+    pub active: bool,//Attestations from an inactive accreditor no longer count
+    pub bump: u8,
+}
+
+impl Accreditor {
+    pub const MAX_NAME_LEN: usize = 64;
+}
@@ -1,32 +1,275 @@
 use anchor_lang::prelude::*;
+use crate::state::{MetaverfAccount, Plan};
+use crate::events::CollegeDeactivated;
+use crate::error::CertificateError;
+use crate::utils::read_field;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct CollectionInfo {
     pub collection: Pubkey,
     pub bump: u8,
+    #[max_len(64)]
     pub name: String,
+    #[max_len(128)]
     pub uri: String,
 }
 
+// What is left of the payments in one mint for the time from `paid_from` to `paid_until`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct Payment {
+    pub mint: Pubkey,//`CollegeAccount::PAID_IN_SOL` for lamports
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DeactivationReason {
+    None,
+    Expired,//The subscription lapsed past the grace period
+    Suspended,//A college moderator suspended the college
+}
+
+// New fields must only be appended so `migrate_college` can grow older accounts in place
 #[account]
+#[derive(InitSpace)]
 pub struct CollegeAccount {
-    pub id: u16,
-    pub authority: Pubkey,
-    pub update_authority: Pubkey,
+    pub legacy_id: u16,//The ID picked by the caller before IDs were assigned, 0 for newer colleges
+    pub authority: Pubkey,//Runs the college's account, rotated with `propose_college_authority`
+    pub update_authority: Pubkey,//Controls metadata on collections owned by the college PDA
     pub last_payment: i64,
     pub active: bool,
     pub bump: u8,
+    #[max_len(10)]
     pub collections: Vec<CollectionInfo>,
+    pub version: u8,//Layout version, 0 for accounts created before versioning
+    pub deactivated_at: i64,//When the college was last deactivated, 0 while active
+    pub deactivation_reason: DeactivationReason,//Why the college was last deactivated
+    pub paid_until: i64,//When the paid subscription runs out, renewals extend it
+    pub plan: Option<Pubkey>,//The college's plan, `None` for the protocol's default annual fee and limits
+    pub usage_period_start: i64,//When the current certificate quota period started
+    pub certificates_this_period: u32,//Certificates minted since `usage_period_start`
+    pub certificates_issued: u64,//Certificates minted over the college's lifetime
+    pub fee_vault_mint: Option<Pubkey>,//The mint of the college's fee vault, set while `auto_renew` may pay from it
+    pub college_id: u64,//Assigned from `MetaverfAccount::next_college_id`, or the legacy ID widened
+    pub pending_authority: Option<Pubkey>,//The proposed next authority, set until it accepts or the transfer is cancelled
+    #[max_len(4)]
+    pub payments: Vec<Payment>,//What the college paid for the time from `paid_from` to `paid_until`, one entry per mint, refunds are priced from it
+    pub paid_from: i64,//When the time `payments` cover started
 }
 
 impl CollegeAccount {
-    pub const INIT_SPACE: usize = 
-        2 +  // id: u16
-        32 + // authority: Pubkey
-        32 + // update_authority: Pubkey
-        8 +  // last_payment: i64
-        1 +  // active: bool
-        1 +  // bump: u8
-        4 +  // collections: Vec length
-        10 * (32 + 1 + 64 + 128); // 10 collections max (Pubkey + bump + name + uri)
-}
\ No newline at end of file
+    pub const VERSION: u8 = 1;
+    pub const MAX_COLLECTIONS: usize = 10;
+    pub const MAX_PAYMENT_MINTS: usize = 4;
+    pub const PAID_IN_SOL: Pubkey = Pubkey::new_from_array([0; 32]);//`Payment::mint` for payments in lamports
+
+    // Reads the account at the baseline layout or at the current one. Baseline colleges end after
+    // `collections`, followed by zero padding or nothing at all, where newer ones store their version.
+    pub fn deserialize_any_version(data: &[u8]) -> Result<Self> {
+        let buf = &mut data.get(8..).unwrap_or_default();
+
+        let legacy_id = read_field(buf)?;
+        let authority = read_field(buf)?;
+        let update_authority = read_field(buf)?;
+        let last_payment = read_field(buf)?;
+        let active = read_field(buf)?;
+        let bump = read_field(buf)?;
+        let collections = read_field(buf)?;
+        if buf.first().is_some_and(|version| *version > 0) {
+            return Self::try_deserialize(&mut &data[..]);
+        }
+
+        Ok(Self {
+            legacy_id,
+            authority,
+            update_authority,
+            last_payment,
+            active,
+            bump,
+            collections,
+            version: 0,
+            deactivated_at: 0,
+            deactivation_reason: DeactivationReason::None,
+            paid_until: 0,
+            plan: None,
+            usage_period_start: 0,
+            certificates_this_period: 0,
+            certificates_issued: 0,
+            fee_vault_mint: None,
+            college_id: 0,
+            pending_authority: None,
+            payments: Vec::new(),
+            paid_from: 0,
+        })
+    }
+
+    // Fills in the fields added since the stored version
+    pub fn upgrade(&mut self, protocol: &MetaverfAccount) {
+        if self.version < 1 {
+            self.paid_until = self.expires_at(protocol);
+            self.college_id = self.legacy_id as u64;
+        }
+
+        self.version = Self::VERSION;
+    }
+
+    pub fn new(
+        college_id: u64,
+        authority: Pubkey,
+        plan: Option<Pubkey>,
+        protocol: &MetaverfAccount,
+        now: i64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            legacy_id: 0,
+            authority,
+            last_payment: now,
+            active: true,
+            bump,
+            update_authority: authority,
+            collections: Vec::new(),
+            version: Self::VERSION,
+            deactivated_at: 0,
+            deactivation_reason: DeactivationReason::None,
+            paid_until: now + protocol.subscription_duration,
+            plan,
+            usage_period_start: now,
+            certificates_this_period: 0,
+            certificates_issued: 0,
+            fee_vault_mint: None,
+            college_id,
+            pending_authority: None,
+            payments: Vec::new(),
+            paid_from: now,
+        }
+    }
+
+    // Colleges without a plan keep the protocol-wide limit
+    pub fn collection_limit(plan: Option<&Plan>) -> usize {
+        plan.map_or(Self::MAX_COLLECTIONS, |plan| plan.max_collections as usize)
+    }
+
+    // The plan account itself is matched against `plan` by the instruction's constraints
+    pub fn require_plan(&self, plan: Option<&Plan>) -> Result<()> {
+        require!(self.plan.is_none() || plan.is_some(), CertificateError::InvalidPlan);
+
+        Ok(())
+    }
+
+    pub fn expires_at(&self, protocol: &MetaverfAccount) -> i64 {
+        // Colleges from before `paid_until` existed expire one period after their last payment until migrated
+        if self.paid_until == 0 {
+            return self.last_payment.saturating_add(protocol.subscription_duration);
+        }
+
+        self.paid_until
+    }
+
+    // Renewing before the college has lapsed stacks onto the time it has already paid for
+    pub fn extend_subscription(&mut self, protocol: &MetaverfAccount, periods: u8, now: i64) -> Result<()> {
+        let start = if self.is_lapsed(protocol, now) {
+            now
+        } else {
+            self.expires_at(protocol)
+        };
+
+        self.paid_until = protocol
+            .subscription_duration
+            .checked_mul(periods as i64)
+            .and_then(|paid| start.checked_add(paid))
+            .ok_or(CertificateError::MathOverflow)?;
+        self.last_payment = now;
+
+        Ok(())
+    }
+
+    // Must run before a renewal moves `paid_until`. What is left of earlier payments carries over in
+    // the mint it was paid in, so paying in another mint keeps it refundable.
+    pub fn record_payment(&mut self, mint: Pubkey, amount: u64, now: i64) -> Result<()> {
+        self.carry_payments(now);
+
+        match self.payments.iter_mut().find(|payment| payment.mint == mint) {
+            Some(payment) => {
+                payment.amount = payment.amount.checked_add(amount).ok_or(CertificateError::MathOverflow)?;
+            }
+            None if amount > 0 => {
+                require!(
+                    self.payments.len() < Self::MAX_PAYMENT_MINTS,
+                    CertificateError::TooManyPaymentMints
+                );
+                self.payments.push(Payment { mint, amount });
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    // Restarts the payments at `now` with what is left of them. Must run before `paid_until` moves.
+    pub fn carry_payments(&mut self, now: i64) {
+        self.payments = self.unused_payments(now);
+        self.paid_from = now;
+    }
+
+    // The part of each payment for the time still left at `now`, payments with nothing left are dropped
+    pub fn unused_payments(&self, now: i64) -> Vec<Payment> {
+        let covered = self.paid_until.saturating_sub(self.paid_from);
+        let left = self.paid_until.saturating_sub(now.max(self.paid_from));
+        if covered <= 0 || left <= 0 {
+            return Vec::new();
+        }
+
+        self.payments
+            .iter()
+            .map(|payment| Payment {
+                mint: payment.mint,
+                amount: (payment.amount as u128 * left as u128 / covered as u128) as u64,
+            })
+            .filter(|payment| payment.amount > 0)
+            .collect()
+    }
+
+    // Past expiry and past the grace period on top of it
+    pub fn is_lapsed(&self, protocol: &MetaverfAccount, now: i64) -> bool {
+        now > self.expires_at(protocol).saturating_add(protocol.grace_period)
+    }
+
+    // Starts a new quota period once the current one has run for a subscription duration
+    pub fn roll_usage_period(&mut self, protocol: &MetaverfAccount, now: i64) {
+        if now >= self.usage_period_start.saturating_add(protocol.subscription_duration) {
+            self.usage_period_start = now;
+            self.certificates_this_period = 0;
+        }
+    }
+
+    pub fn record_certificate(&mut self) -> Result<()> {
+        self.certificates_this_period = self
+            .certificates_this_period
+            .checked_add(1)
+            .ok_or(CertificateError::MathOverflow)?;
+        self.certificates_issued = self
+            .certificates_issued
+            .checked_add(1)
+            .ok_or(CertificateError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn deactivate(&mut self, reason: DeactivationReason, now: i64) {
+        self.active = false;
+        self.deactivated_at = now;
+        self.deactivation_reason = reason;
+
+        emit!(CollegeDeactivated {
+            college_id: self.college_id,
+            reason,
+            deactivated_at: now,
+        });
+    }
+
+    pub fn activate(&mut self) {
+        self.active = true;
+        self.deactivated_at = 0;
+        self.deactivation_reason = DeactivationReason::None;
+    }
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+// Maps a college authority to its college, so wallets need not search college IDs
+#[account]
+#[derive(InitSpace)]
+pub struct CollegeLookup {
+    pub college_id: u64,
+    pub college: Pubkey,//The college's `CollegeAccount`
+    pub bump: u8,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::error::CertificateError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CollegeProfileArgs {
+    pub name: String,
+    pub country_code: String,
+    pub website: String,
+    pub logo_uri: String,
+    pub accrediting_body: String,
+}
+
+// Public details about a college, shown to verifiers next to its certificates
+#[account]
+#[derive(InitSpace)]
+pub struct CollegeProfile {
+    pub college: Pubkey,//The college's `CollegeAccount`
+    #[max_len(64)]
+    pub name: String,
+    #[max_len(2)]
+    pub country_code: String,//ISO 3166-1 alpha-2, e.g. "IN"
+    #[max_len(128)]
+    pub website: String,
+    #[max_len(128)]
+    pub logo_uri: String,
+    #[max_len(64)]
+    pub accrediting_body: String,
+    pub bump: u8,
+}
+
+impl CollegeProfile {
+    pub fn set(&mut self, args: CollegeProfileArgs) -> Result<()> {
+        require!(
+            !args.name.is_empty()
+                && args.name.len() <= 64
+                && args.website.len() <= 128
+                && args.logo_uri.len() <= 128
+                && args.accrediting_body.len() <= 64,
+            CertificateError::InvalidCollegeProfile
+        );
+        require!(
+            args.country_code.len() == 2 && args.country_code.bytes().all(|b| b.is_ascii_uppercase()),
+            CertificateError::InvalidCollegeProfile
+        );
+
+        self.name = args.name;
+        self.country_code = args.country_code;
+        self.website = args.website;
+        self.logo_uri = args.logo_uri;
+        self.accrediting_body = args.accrediting_body;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::error::CertificateError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum CouponDiscount {
+    Percent { bps: u16 },//Off the total, in basis points
+    Fixed { amount: u64 },//Off the total, in payment mint base units
+}
+
+// A promo code the admin hands out for registration and renewal discounts
+#[account]
+#[derive(InitSpace)]
+pub struct Coupon {
+    #[max_len(32)]
+    pub code: String,
+    pub discount: CouponDiscount,
+    pub max_uses: u32,//0 for unlimited
+    pub uses: u32,
+    pub expires_at: i64,//0 for never
+    #[max_len(10)]
+    pub allowed_colleges: Vec<u64>,//College IDs that may redeem it, empty for any
+    pub active: bool,//Coupons created in council mode need a council proposal to go live
+    pub bump: u8,
+}
+
+impl Coupon {
+    pub const MAX_CODE_LEN: usize = 32;
+    pub const MAX_ALLOWED_COLLEGES: usize = 10;
+
+    // Checks the coupon may be used by `college_id` and counts the use
+    pub fn redeem(&mut self, college_id: u64, now: i64) -> Result<CouponDiscount> {
+        require!(self.active, CertificateError::InvalidCoupon);
+        require!(self.expires_at == 0 || now < self.expires_at, CertificateError::CouponExpired);
+        require!(self.max_uses == 0 || self.uses < self.max_uses, CertificateError::CouponExhausted);
+        require!(
+            self.allowed_colleges.is_empty() || self.allowed_colleges.contains(&college_id),
+            CertificateError::InvalidCoupon
+        );
+
+        self.uses = self.uses.checked_add(1).ok_or(CertificateError::MathOverflow)?;
+
+        Ok(self.discount)
+    }
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::error::CertificateError;
+use crate::state::CollegeAccount;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct IssuerDelegateArgs {
+    pub delegate: Pubkey,
+    pub collections: Vec<Pubkey>,
+    pub expires_at: i64,
+    pub daily_cap: u32,
+}
+
+// Lets a staff key mint into some of a college's collections, created and revoked by the college authority
+#[account]
+#[derive(InitSpace)]
+pub struct IssuerDelegate {
+    pub college: Pubkey,//The college's `CollegeAccount`
+    pub delegate: Pubkey,//The staff key allowed to sign `mint_certificate`
+    #[max_len(10)]
+    pub collections: Vec<Pubkey>,//The collections it may mint into
+    pub expires_at: i64,//Minting stops at this timestamp
+    pub daily_cap: u32,//Certificates allowed per day, 0 for no cap
+    pub day_start: i64,//Start of the day `minted_today` counts
+    pub minted_today: u32,
+    pub certificates_issued: u64,//Certificates minted under this delegation
+    pub bump: u8,
+    pub granted_by: Pubkey,//The college authority that created it, a rotated authority's delegates stop working
+}
+
+impl IssuerDelegate {
+    pub const DAY: i64 = 86_400;
+
+    pub fn new(college: Pubkey, granted_by: Pubkey, args: IssuerDelegateArgs, now: i64, bump: u8) -> Result<Self> {
+        require!(
+            !args.collections.is_empty()
+                && args.collections.len() <= CollegeAccount::MAX_COLLECTIONS
+                && args.expires_at > now,
+            CertificateError::InvalidIssuerDelegate
+        );
+
+        Ok(Self {
+            college,
+            delegate: args.delegate,
+            collections: args.collections,
+            expires_at: args.expires_at,
+            daily_cap: args.daily_cap,
+            day_start: 0,
+            minted_today: 0,
+            certificates_issued: 0,
+            bump,
+            granted_by,
+        })
+    }
+
+    // Checks the delegation covers a mint into `collection` now and counts it
+    pub fn record_certificate(&mut self, collection: &Pubkey, now: i64) -> Result<()> {
+        require!(now < self.expires_at, CertificateError::IssuerDelegateExpired);
+        require!(
+            self.collections.contains(collection),
+            CertificateError::CollectionNotDelegated
+        );
+
+        let today = now - now.rem_euclid(Self::DAY);
+        if today != self.day_start {
+            self.day_start = today;
+            self.minted_today = 0;
+        }
+        require!(
+            self.daily_cap == 0 || self.minted_today < self.daily_cap,
+            CertificateError::DailyCapReached
+        );
+
+        self.minted_today = self.minted_today.checked_add(1).ok_or(CertificateError::MathOverflow)?;
+        self.certificates_issued = self.certificates_issued.checked_add(1).ok_or(CertificateError::MathOverflow)?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,42 @@
 pub mod protocol;
-pub use protocol::MetaverfAccount;
+pub use protocol::{MetaverfAccount, PayoutRecipient, PrepayDiscount};
 
 pub mod college;
-pub use college::CollegeAccount;
+pub use college::{CollegeAccount, DeactivationReason, Payment};
 // pub use college::CollectionInfo;
 
+pub mod proposal;
+pub use proposal::{CouncilProposal, ProposalAction};
+
+pub mod pending_parameters;
+pub use pending_parameters::PendingParameters;
+
+pub mod accepted_mint;
+pub use accepted_mint::AcceptedMint;
+
+pub mod role;
+pub use role::{Role, RoleAssignment};
+
+pub mod plan;
+pub use plan::Plan;
+
+pub mod tombstone;
+pub use tombstone::CollegeTombstone;
+
+pub mod coupon;
+pub use coupon::{Coupon, CouponDiscount};
+
+pub mod college_lookup;
+pub use college_lookup::CollegeLookup;
+
+pub mod college_profile;
+pub use college_profile::{CollegeProfile, CollegeProfileArgs};
+
+pub mod issuer_delegate;
+pub use issuer_delegate::{IssuerDelegate, IssuerDelegateArgs};
+
+pub mod accreditor;
+pub use accreditor::Accreditor;
+
+pub mod accreditation;
+pub use accreditation::{Accreditation, AccreditationStatus};
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::error::CertificateError;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PendingParameters {
+    pub annual_fee: Option<u64>,//The queued annual fee, if it changes
+    pub subscription_duration: Option<i64>,//The queued subscription duration, if it changes
+    pub effective_at: i64,//From when `apply_parameters` may activate the queued values
+    pub bump: u8,
+    pub grace_period: Option<i64>,//The queued grace period, if it changes
+    pub certificate_fee: Option<u64>,//The queued per-certificate fee, if it changes
+}
+
+impl PendingParameters {
+    pub fn is_pending(&self) -> bool {
+        self.annual_fee.is_some()
+            || self.subscription_duration.is_some()
+            || self.grace_period.is_some()
+            || self.certificate_fee.is_some()
+    }
+
+    // Queuing again replaces the previous change and restarts the notice window
+    pub fn queue(
+        &mut self,
+        annual_fee: Option<u64>,
+        subscription_duration: Option<i64>,
+        grace_period: Option<i64>,
+        certificate_fee: Option<u64>,
+        effective_at: i64,
+    ) -> Result<()> {
+        // Expiry, proration and refunds all divide by the duration
+        require!(
+            subscription_duration.is_none_or(|duration| duration > 0),
+            CertificateError::InvalidSubscriptionDuration
+        );
+
+        self.annual_fee = annual_fee;
+        self.subscription_duration = subscription_duration;
+        self.grace_period = grace_period;
+        self.certificate_fee = certificate_fee;
+        self.effective_at = effective_at;
+
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.annual_fee = None;
+        self.subscription_duration = None;
+        self.grace_period = None;
+        self.certificate_fee = None;
+    }
+}
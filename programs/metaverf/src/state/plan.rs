@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+// An admin-defined subscription tier, priced in the protocol's payment mint
+#[account]
+#[derive(InitSpace)]
+pub struct Plan {
+    pub id: u8,
+    #[max_len(32)]
+    pub name: String,
+    pub price: u64,//Price per subscription period
+    pub pending_price: Option<u64>,//A queued price change, activated by `apply_plan_price`
+    pub price_effective_at: i64,//From when the queued price may be applied
+    pub max_collections: u8,//At most `CollegeAccount::MAX_COLLECTIONS`
+    pub yearly_certificate_quota: u32,//Certificates per subscription period, 0 for unlimited
+    pub active: bool,//Inactive plans cannot be picked, existing colleges keep them
+    pub bump: u8,
+}
+
+impl Plan {
+    pub const MAX_NAME_LEN: usize = 32;
+
+    // Activation and limits apply immediately, price changes wait for the protocol's parameter delay
+    pub fn update(
+        &mut self,
+        price: Option<u64>,
+        max_collections: Option<u8>,
+        yearly_certificate_quota: Option<u32>,
+        active: Option<bool>,
+        effective_at: i64,
+    ) {
+        if let Some(price) = price {
+            self.pending_price = Some(price);
+            self.price_effective_at = effective_at;
+        }
+
+        if let Some(max_collections) = max_collections {
+            self.max_collections = max_collections;
+        }
+
+        if let Some(quota) = yearly_certificate_quota {
+            self.yearly_certificate_quota = quota;
+        }
+
+        if let Some(active) = active {
+            self.active = active;
+        }
+    }
+
+    pub fn allows_certificate(&self, issued_this_period: u32) -> bool {
+        self.yearly_certificate_quota == 0 || issued_this_period < self.yearly_certificate_quota
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{PayoutRecipient, PrepayDiscount};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub enum ProposalAction {
+    UpdateParameters {
+        annual_fee: Option<u64>,
+        subscription_duration: Option<i64>,
+        grace_period: Option<i64>,
+        certificate_fee: Option<u64>,
+    },
+    WithdrawFees {
+        amount: u64,
+        destination: Pubkey,//The token account receiving the fees
+    },
+    SetCouncil {
+        #[max_len(10)]
+        members: Vec<Pubkey>,
+        threshold: u8,
+    },
+    UpdateAcceptedMint {
+        mint: Pubkey,
+        annual_fee: Option<u64>,
+        enabled: Option<bool>,
+    },
+    SetPayoutRecipients {
+        #[max_len(5)]
+        recipients: Vec<PayoutRecipient>,
+    },
+    SetPrepayDiscounts {
+        #[max_len(5)]
+        discounts: Vec<PrepayDiscount>,
+    },
+    SetRefundPolicy {
+        refund_bps: u16,
+    },
+    SetCouponActive {
+        #[max_len(32)]
+        code: String,
+        active: bool,
+    },
+    WithdrawSol {
+        amount: u64,
+        destination: Pubkey,//The wallet receiving the lamports
+    },
+    SetSolPriceFeed {
+        feed: Pubkey,
+        max_age: i64,
+        max_confidence_bps: u16,
+    },
+    UpdatePlan {
+        plan_id: u8,
+        price: Option<u64>,
+        max_collections: Option<u8>,
+        yearly_certificate_quota: Option<u32>,
+        active: Option<bool>,
+    },
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CouncilProposal {
+    pub id: u64,
+    pub proposer: Pubkey,//Receives the rent back once the proposal is executed
+    pub action: ProposalAction,
+    #[max_len(10)]
+    pub approvals: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl CouncilProposal {
+    // Approvals from keys that have since left the council no longer count
+    pub fn approvals_from(&self, council: &[Pubkey]) -> usize {
+        self.approvals.iter().filter(|key| council.contains(key)).count()
+    }
+}
@@ -1,12 +1,304 @@
 use anchor_lang::prelude::*;
+use crate::error::CertificateError;
+use crate::state::{AcceptedMint, CouponDiscount, Plan};
+use crate::utils::read_field;
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct PrepayDiscount {
+    pub min_periods: u8,//Renewals of at least this many periods get the discount
+    pub discount_bps: u16,//Discount on the total in basis points
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct PayoutRecipient {
+    pub owner: Pubkey,//Receives its share into a token account it owns
+    pub share_bps: u16,//Share of each distribution in basis points
+}
 
+// New fields must only be appended so `migrate_protocol` can grow older accounts in place
 #[account]
 #[derive(InitSpace)]
 pub struct MetaverfAccount {
-    
-    pub uni_no: u16,//To keep the track of the Uni
     pub annual_fee: u64,//The annual fee my protocol is going to charge
     pub verf_bump: u8,//The bump of the protocol
-    pub subscription_duration: i64//The subscription duration of the protocol
+    pub subscription_duration: i64,//The subscription duration of the protocol
+    pub admin: Pubkey,//The key allowed to run privileged protocol instructions
+    pub pending_admin: Option<Pubkey>,//The proposed next admin, set until it accepts or the transfer is cancelled
+    #[max_len(10)]
+    pub council: Vec<Pubkey>,//The council members, empty unless council mode is on
+    pub council_threshold: u8,//Approvals needed to execute a proposal, 0 when council mode is off
+    pub proposal_count: u64,//The id of the next council proposal
+    pub parameter_delay: i64,//The minimum notice before a parameter change can be applied
+    pub paused: bool,//Emergency stop, blocks registration, renewal and issuance while set
+    pub payment_mint: Pubkey,//The primary mint fees are paid in, its ATA owned by this account is the treasury
+    pub version: u8,//Layout version, 0 for accounts created before versioning
+    #[max_len(5)]
+    pub payout_recipients: Vec<PayoutRecipient>,//How `distribute_fees` splits the treasury, empty until configured
+    pub grace_period: i64,//How long after expiry a college may keep issuing before it is deactivated
+    #[max_len(5)]
+    pub prepay_discounts: Vec<PrepayDiscount>,//Discounts for renewing several periods at once
+    pub certificate_fee: u64,//Charged in the payment mint for every certificate minted, 0 turns metering off
+    pub refund_bps: u16,//Share of the unused subscription refunded by `close_college`, 0 turns refunds off
+    pub sol_price_feed: Pubkey,//The Pyth-format SOL/USD price account, the default key turns SOL payments off
+    pub max_price_age: i64,//Oldest oracle price accepted, in seconds
+    pub max_price_confidence_bps: u16,//Widest confidence interval accepted, relative to the price
+    pub next_college_id: u64,//The ID the next registered college gets
+}
+
+impl MetaverfAccount {
+    pub const VERSION: u8 = 1;
+    pub const MAX_COUNCIL_MEMBERS: usize = 10;
+    pub const MAX_PAYOUT_RECIPIENTS: usize = 5;
+    pub const MAX_PREPAY_DISCOUNTS: usize = 5;
+    pub const MAX_RENEWAL_PERIODS: u8 = 10;
+    pub const FIRST_COLLEGE_ID: u64 = 1 << 16;//Above every ID callers could pick as a u16
+    pub const BASELINE_LEN: usize = 19;//The layout from before versioning, up to `subscription_duration`
+
+    // Reads the account at the baseline layout, told apart by its size, or at the current one
+    pub fn deserialize_any_version(data: &[u8]) -> Result<Self> {
+        if data.len() != 8 + Self::BASELINE_LEN {
+            return Self::try_deserialize(&mut &data[..]);
+        }
+
+        let buf = &mut &data[8..];
+        // The baseline's college counter, colleges get `next_college_id` now
+        let _uni_no: u16 = read_field(buf)?;
+
+        Ok(Self {
+            annual_fee: read_field(buf)?,
+            verf_bump: read_field(buf)?,
+            subscription_duration: read_field(buf)?,
+            admin: Pubkey::default(),
+            pending_admin: None,
+            council: Vec::new(),
+            council_threshold: 0,
+            proposal_count: 0,
+            parameter_delay: 0,
+            paused: false,
+            payment_mint: Pubkey::default(),
+            version: 0,
+            payout_recipients: Vec::new(),
+            grace_period: 0,
+            prepay_discounts: Vec::new(),
+            certificate_fee: 0,
+            refund_bps: 0,
+            sol_price_feed: Pubkey::default(),
+            max_price_age: 0,
+            max_price_confidence_bps: 0,
+            next_college_id: 0,
+        })
+    }
+
+    // Fills in the fields added since the stored version. The baseline had no admin and only
+    // recorded its payment mint in the treasury it created.
+    pub fn upgrade(&mut self, admin: Pubkey, payment_mint: Pubkey) {
+        if self.version < 1 {
+            self.admin = admin;
+            self.payment_mint = payment_mint;
+            self.next_college_id = Self::FIRST_COLLEGE_ID;
+        }
+
+        self.version = Self::VERSION;
+    }
+
+    pub fn assign_college_id(&mut self) -> Result<u64> {
+        let college_id = self.next_college_id;
+        self.next_college_id = college_id.checked_add(1).ok_or(CertificateError::MathOverflow)?;
+
+        Ok(college_id)
+    }
+
+    pub fn council_enabled(&self) -> bool {
+        self.council_threshold > 0
+    }
+
+    // While paused, fees may only leave the treasury into a token account owned by the admin
+    pub fn can_withdraw_to(&self, owner: &Pubkey) -> bool {
+        !self.paused || *owner == self.admin
+    }
+
+    pub fn is_council_member(&self, key: &Pubkey) -> bool {
+        self.council.contains(key)
+    }
+
+    // An empty council with a zero threshold turns council mode off
+    pub fn set_council(&mut self, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        require!(
+            members.len() <= Self::MAX_COUNCIL_MEMBERS && (threshold as usize) <= members.len(),
+            CertificateError::InvalidCouncil
+        );
+        require!(
+            (threshold == 0) == members.is_empty(),
+            CertificateError::InvalidCouncil
+        );
+        require!(
+            members.iter().enumerate().all(|(i, key)| !members[..i].contains(key)),
+            CertificateError::InvalidCouncil
+        );
+
+        self.council = members;
+        self.council_threshold = threshold;
+
+        Ok(())
+    }
+
+    // The annual fee in `mint`, which is either the payment mint or an enabled accepted mint.
+    // Plan prices are set in the payment mint and scale by the accepted mint's own fee for other mints.
+    pub fn annual_fee_in(
+        &self,
+        mint: &Pubkey,
+        accepted_mint: Option<&AcceptedMint>,
+        plan: Option<&Plan>,
+    ) -> Result<u64> {
+        if *mint == self.payment_mint {
+            return Ok(plan.map_or(self.annual_fee, |plan| plan.price));
+        }
+
+        let accepted = match accepted_mint {
+            Some(accepted) if accepted.mint == *mint && accepted.enabled => accepted,
+            _ => return err!(CertificateError::InvalidPaymentMint),
+        };
+
+        match plan {
+            Some(plan) => self.scale_to(accepted, plan.price),
+            None => Ok(accepted.annual_fee),
+        }
+    }
+
+    // Converts a payment mint amount into `accepted` at the ratio of the two annual fees
+    fn scale_to(&self, accepted: &AcceptedMint, amount: u64) -> Result<u64> {
+        require!(self.annual_fee > 0, CertificateError::MathOverflow);
+        let scaled = amount as u128 * accepted.annual_fee as u128 / self.annual_fee as u128;
+
+        u64::try_from(scaled).map_err(|_| error!(CertificateError::MathOverflow))
+    }
+
+    // `cost` in `mint` after a coupon's discount, never below zero
+    pub fn apply_coupon(
+        &self,
+        cost: u64,
+        discount: CouponDiscount,
+        mint: &Pubkey,
+        accepted_mint: Option<&AcceptedMint>,
+    ) -> Result<u64> {
+        let off = match discount {
+            CouponDiscount::Percent { bps } => {
+                u64::try_from(cost as u128 * bps as u128 / 10_000).map_err(|_| error!(CertificateError::MathOverflow))?
+            }
+            CouponDiscount::Fixed { amount } if *mint == self.payment_mint => amount,
+            CouponDiscount::Fixed { amount } => {
+                let accepted = accepted_mint.ok_or(CertificateError::InvalidPaymentMint)?;
+                self.scale_to(accepted, amount)?
+            }
+        };
+
+        Ok(cost.saturating_sub(off))
+    }
+
+    // Shares must add up to exactly 10_000 bps, an empty list turns distribution off
+    pub fn set_payout_recipients(&mut self, recipients: Vec<PayoutRecipient>) -> Result<()> {
+        require!(
+            recipients.len() <= Self::MAX_PAYOUT_RECIPIENTS,
+            CertificateError::InvalidPayoutConfig
+        );
+        require!(
+            recipients.is_empty()
+                || recipients.iter().map(|r| r.share_bps as u32).sum::<u32>() == 10_000,
+            CertificateError::InvalidPayoutConfig
+        );
+        require!(
+            recipients
+                .iter()
+                .enumerate()
+                .all(|(i, r)| !recipients[..i].iter().any(|other| other.owner == r.owner)),
+            CertificateError::InvalidPayoutConfig
+        );
+
+        self.payout_recipients = recipients;
+
+        Ok(())
+    }
+
+    pub fn set_prepay_discounts(&mut self, discounts: Vec<PrepayDiscount>) -> Result<()> {
+        require!(
+            discounts.len() <= Self::MAX_PREPAY_DISCOUNTS
+                && discounts.iter().all(|d| d.min_periods > 0 && d.discount_bps < 10_000),
+            CertificateError::InvalidPrepayDiscounts
+        );
+
+        self.prepay_discounts = discounts;
+
+        Ok(())
+    }
+
+    // `periods` times the per-period fee, less the best discount the renewal qualifies for
+    pub fn renewal_cost(&self, annual_fee: u64, periods: u8) -> Result<u64> {
+        require!(
+            periods > 0 && periods <= Self::MAX_RENEWAL_PERIODS,
+            CertificateError::InvalidRenewalPeriods
+        );
+
+        let discount_bps = self
+            .prepay_discounts
+            .iter()
+            .filter(|d| periods >= d.min_periods)
+            .map(|d| d.discount_bps)
+            .max()
+            .unwrap_or(0);
+
+        let total = annual_fee as u128 * periods as u128 * (10_000 - discount_bps) as u128 / 10_000;
+
+        u64::try_from(total).map_err(|_| error!(CertificateError::MathOverflow))
+    }
+
+    pub fn set_refund_policy(&mut self, refund_bps: u16) -> Result<()> {
+        require!(refund_bps <= 10_000, CertificateError::InvalidRefundPolicy);
+        self.refund_bps = refund_bps;
+
+        Ok(())
+    }
+
+    // The share of a college's unused payment the refund policy pays back
+    pub fn refund_for(&self, unused: u64) -> Result<u64> {
+        let refund = unused as u128 * self.refund_bps as u128 / 10_000;
+
+        u64::try_from(refund).map_err(|_| error!(CertificateError::MathOverflow))
+    }
+
+    pub fn set_sol_price_feed(&mut self, feed: Pubkey, max_age: i64, max_confidence_bps: u16) -> Result<()> {
+        require!(
+            max_age >= 0 && max_confidence_bps <= 10_000,
+            CertificateError::InvalidPriceFeed
+        );
+
+        self.sol_price_feed = feed;
+        self.max_price_age = max_age;
+        self.max_price_confidence_bps = max_confidence_bps;
+
+        Ok(())
+    }
+
+    pub fn update_parameters(
+        &mut self,
+        annual_fee: Option<u64>,
+        subscription_duration: Option<i64>,
+        grace_period: Option<i64>,
+        certificate_fee: Option<u64>,
+    ) {
+        if let Some(fee) = annual_fee {
+            self.annual_fee = fee;
+        }
+
+        if let Some(duration) = subscription_duration {
+            self.subscription_duration = duration;
+        }
+
+        if let Some(grace) = grace_period {
+            self.grace_period = grace;
+        }
+
+        if let Some(fee) = certificate_fee {
+            self.certificate_fee = fee;
+        }
+    }
 }
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    FeeManager,//May queue parameter changes
+    Treasurer,//May withdraw fees
+    Pauser,//May pause and unpause the protocol
+    CollegeModerator,//May suspend and reinstate colleges
+}
+
+impl Role {
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            Role::FeeManager => b"fee_manager",
+            Role::Treasurer => b"treasurer",
+            Role::Pauser => b"pauser",
+            Role::CollegeModerator => b"college_moderator",
+        }
+    }
+}
+
+// Exists while `holder` has `role`, granted and revoked by the protocol admin
+#[account]
+#[derive(InitSpace)]
+pub struct RoleAssignment {
+    pub role: Role,
+    pub holder: Pubkey,
+    pub bump: u8,
+}
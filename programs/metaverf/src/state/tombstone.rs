@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+// Left behind by `close_college` so the college ID is never registered again
+#[account]
+#[derive(InitSpace)]
+pub struct CollegeTombstone {
+    pub college_id: u64,
+    pub authority: Pubkey,//The authority that closed the college
+    pub closed_at: i64,
+    #[max_len(10)]
+    pub collections: Vec<Pubkey>,//The college's collections, so certificates in them stay attributable
+    pub certificates_issued: u64,//Certificates minted over the college's lifetime
+    pub bump: u8,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::error::ErrorCode;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::error::CertificateError;
+
+// Reads the next field of an older account layout
+pub fn read_field<T: AnchorDeserialize>(buf: &mut &[u8]) -> Result<T> {
+    T::deserialize(buf).map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))
+}
+
+// Grows `account` to `new_len` zeroed bytes, topping up rent from `payer`
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    new_len: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+
+    if rent_due > 0 {
+        let cpi_accounts = Transfer {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+
+        transfer(CpiContext::new(system_program.clone(), cpi_accounts), rent_due)?;
+    }
+
+    account.realloc(new_len, true)?;
+
+    Ok(())
+}
+
+// Writes `value` over zeroed data so nothing of a longer, older serialization is left behind
+pub fn rewrite_account<T: AccountSerialize>(account: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+
+    value.try_serialize(&mut &mut data[..])
+}
+
+// Closes an account owned by this program that is not deserialized as an `Account`
+pub fn close_program_account<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(CertificateError::MathOverflow)?;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&anchor_lang::system_program::ID);
+    account.realloc(0, false)?;
+
+    Ok(())
+}
+
+// Sends lamports out of the protocol's SOL vault PDA
+pub fn transfer_from_sol_vault<'info>(
+    sol_vault: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let (_, bump) = Pubkey::find_program_address(&[b"sol_vault"], &crate::ID);
+    let seeds: &[&[u8]] = &[b"sol_vault", &[bump]];
+
+    let cpi_accounts = Transfer {
+        from: sol_vault.clone(),
+        to: to.clone(),
+    };
+
+    transfer(
+        CpiContext::new_with_signer(system_program.clone(), cpi_accounts, &[seeds]),
+        amount,
+    )
+}
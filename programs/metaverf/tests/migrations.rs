@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use metaverf::state::{CollegeAccount, DeactivationReason, MetaverfAccount, Payment};
+
+fn serialize<T: AnchorSerialize>(fields: &mut Vec<u8>, value: T) {
+    value.serialize(fields).unwrap();
+}
+
+fn baseline_protocol() -> Vec<u8> {
+    let mut data = MetaverfAccount::DISCRIMINATOR.to_vec();
+    serialize(&mut data, (7u16, 10_000u64, 254u8, 86_400i64));
+    data
+}
+
+fn current_protocol() -> MetaverfAccount {
+    MetaverfAccount {
+        annual_fee: 10_000,
+        verf_bump: 254,
+        subscription_duration: 1_000,
+        admin: Pubkey::new_unique(),
+        pending_admin: None,
+        council: Vec::new(),
+        council_threshold: 0,
+        proposal_count: 0,
+        parameter_delay: 0,
+        paused: false,
+        payment_mint: Pubkey::new_unique(),
+        version: MetaverfAccount::VERSION,
+        payout_recipients: Vec::new(),
+        grace_period: 100,
+        prepay_discounts: Vec::new(),
+        certificate_fee: 5,
+        refund_bps: 2_500,
+        sol_price_feed: Pubkey::new_unique(),
+        max_price_age: 60,
+        max_price_confidence_bps: 100,
+        next_college_id: MetaverfAccount::FIRST_COLLEGE_ID + 3,
+    }
+}
+
+#[test]
+fn baseline_protocol_reads_as_version_zero() {
+    let data = baseline_protocol();
+    assert_eq!(data.len(), 8 + MetaverfAccount::BASELINE_LEN);
+
+    let protocol = MetaverfAccount::deserialize_any_version(&data).unwrap();
+    assert_eq!(protocol.annual_fee, 10_000);
+    assert_eq!(protocol.verf_bump, 254);
+    assert_eq!(protocol.subscription_duration, 86_400);
+    assert_eq!(protocol.admin, Pubkey::default());
+    assert_eq!(protocol.payment_mint, Pubkey::default());
+    assert_eq!(protocol.version, 0);
+}
+
+#[test]
+fn baseline_protocol_upgrades_to_the_current_version() {
+    let admin = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let mut protocol = MetaverfAccount::deserialize_any_version(&baseline_protocol()).unwrap();
+
+    protocol.upgrade(admin, mint);
+
+    assert_eq!(protocol.version, MetaverfAccount::VERSION);
+    assert_eq!(protocol.admin, admin);
+    assert_eq!(protocol.payment_mint, mint);
+    // Baseline colleges picked their own u16 IDs, assigned IDs start above all of them
+    assert_eq!(protocol.next_college_id, MetaverfAccount::FIRST_COLLEGE_ID);
+    assert_eq!(protocol.annual_fee, 10_000);
+    assert!(!protocol.paused);
+
+    // The upgraded account fits the current layout and reads back unchanged
+    let mut data = vec![0; 8 + MetaverfAccount::INIT_SPACE];
+    protocol.try_serialize(&mut &mut data[..]).unwrap();
+    let read = MetaverfAccount::deserialize_any_version(&data).unwrap();
+    assert_eq!(read.admin, admin);
+    assert_eq!(read.version, MetaverfAccount::VERSION);
+}
+
+#[test]
+fn current_protocol_reads_like_the_account_itself() {
+    let protocol = current_protocol();
+    let mut data = vec![0; 8 + MetaverfAccount::INIT_SPACE];
+    protocol.try_serialize(&mut &mut data[..]).unwrap();
+
+    let read = MetaverfAccount::deserialize_any_version(&data).unwrap();
+    assert_eq!(read.version, MetaverfAccount::VERSION);
+    assert_eq!(read.grace_period, 100);
+    assert_eq!(read.refund_bps, 2_500);
+    assert_eq!(read.sol_price_feed, protocol.sol_price_feed);
+    assert_eq!(read.next_college_id, protocol.next_college_id);
+}
+
+fn baseline_college(padding: usize) -> Vec<u8> {
+    let authority = Pubkey::new_unique();
+    let mut data = CollegeAccount::DISCRIMINATOR.to_vec();
+    serialize(&mut data, (42u16, authority, authority));
+    serialize(&mut data, (1_000i64, true, 253u8));
+    serialize(&mut data, 0u32);
+    data.resize(data.len() + padding, 0);
+    data
+}
+
+#[test]
+fn baseline_college_reads_as_version_zero() {
+    // Baseline colleges were allocated 2330 bytes, shorter ones end right after `collections`
+    for padding in [0, 2330 - 80] {
+        let college = CollegeAccount::deserialize_any_version(&baseline_college(padding)).unwrap();
+
+        assert_eq!(college.legacy_id, 42);
+        assert_eq!(college.last_payment, 1_000);
+        assert!(college.active);
+        assert_eq!(college.bump, 253);
+        assert_eq!(college.version, 0);
+        assert_eq!(college.paid_until, 0);
+        assert_eq!(college.college_id, 0);
+    }
+}
+
+#[test]
+fn baseline_college_upgrades_to_the_current_version() {
+    let mut protocol = current_protocol();
+    protocol.subscription_duration = 500;
+    let mut college = CollegeAccount::deserialize_any_version(&baseline_college(64)).unwrap();
+
+    college.upgrade(&protocol);
+
+    assert_eq!(college.version, CollegeAccount::VERSION);
+    assert_eq!(college.college_id, 42);
+    assert_eq!(college.paid_until, 1_500);
+    assert!(college.deactivation_reason == DeactivationReason::None);
+    assert!(college.payments.is_empty());
+}
+
+#[test]
+fn current_college_reads_like_the_account_itself() {
+    let protocol = current_protocol();
+    let mut college = CollegeAccount::new(
+        MetaverfAccount::FIRST_COLLEGE_ID,
+        Pubkey::new_unique(),
+        Some(Pubkey::new_unique()),
+        &protocol,
+        2_000,
+        255,
+    );
+    college.record_payment(protocol.payment_mint, 10_000, 2_000).unwrap();
+    let mut data = vec![0; 8 + CollegeAccount::INIT_SPACE];
+    college.try_serialize(&mut &mut data[..]).unwrap();
+
+    let read = CollegeAccount::deserialize_any_version(&data).unwrap();
+    assert_eq!(read.version, CollegeAccount::VERSION);
+    assert_eq!(read.college_id, MetaverfAccount::FIRST_COLLEGE_ID);
+    assert_eq!(read.plan, college.plan);
+    assert_eq!(read.paid_until, 3_000);
+    assert_eq!(read.payments, vec![Payment { mint: protocol.payment_mint, amount: 10_000 }]);
+}
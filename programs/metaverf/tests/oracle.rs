@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use metaverf::error::CertificateError;
+use metaverf::oracle::PriceFeed;
+
+fn feed(price: i64, expo: i32) -> PriceFeed {
+    PriceFeed {
+        price,
+        conf: 0,
+        expo,
+        publish_time: 0,
+    }
+}
+
+fn assert_error<T>(result: Result<T>, expected: CertificateError) {
+    match result {
+        Err(Error::AnchorError(error)) => assert_eq!(error.error_code_number, u32::from(expected)),
+        Err(error) => panic!("unexpected error {error:?}"),
+        Ok(_) => panic!("expected {expected:?}"),
+    }
+}
+
+#[test]
+fn usd_amounts_convert_at_the_feed_price() {
+    // $150.00000000 per SOL, so 15 USDC buys a tenth of a SOL
+    assert_eq!(feed(15_000_000_000, -8).usd_to_lamports(15_000_000, 6).unwrap(), 100_000_000);
+
+    // A positive exponent scales the other way, $150 per SOL again
+    assert_eq!(feed(15, 1).usd_to_lamports(15_000_000, 6).unwrap(), 100_000_000);
+}
+
+#[test]
+fn conversions_past_the_integer_range_overflow() {
+    // The scale no longer fits a u128
+    assert_error(feed(1, -40).usd_to_lamports(1, 0), CertificateError::MathOverflow);
+    assert_error(feed(1, i32::MAX).usd_to_lamports(1, 6), CertificateError::MathOverflow);
+    // The exponent itself no longer fits an i32
+    assert_error(feed(1, i32::MIN).usd_to_lamports(1, 0), CertificateError::MathOverflow);
+    // The result no longer fits a u64
+    assert_error(feed(1, -8).usd_to_lamports(u64::MAX, 0), CertificateError::MathOverflow);
+}
+
+#[test]
+fn non_positive_prices_are_rejected() {
+    assert_error(feed(0, -8).usd_to_lamports(1_000_000, 6), CertificateError::InvalidPriceFeed);
+    assert_error(feed(-1, -8).usd_to_lamports(1_000_000, 6), CertificateError::InvalidPriceFeed);
+}
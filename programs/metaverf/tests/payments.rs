@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use metaverf::error::CertificateError;
+use metaverf::state::{CollegeAccount, MetaverfAccount, Payment};
+
+fn protocol(payment_mint: Pubkey) -> MetaverfAccount {
+    MetaverfAccount {
+        annual_fee: 1_000,
+        verf_bump: 254,
+        subscription_duration: 1_000,
+        admin: Pubkey::new_unique(),
+        pending_admin: None,
+        council: Vec::new(),
+        council_threshold: 0,
+        proposal_count: 0,
+        parameter_delay: 0,
+        paused: false,
+        payment_mint,
+        version: MetaverfAccount::VERSION,
+        payout_recipients: Vec::new(),
+        grace_period: 0,
+        prepay_discounts: Vec::new(),
+        certificate_fee: 0,
+        refund_bps: 10_000,
+        sol_price_feed: Pubkey::default(),
+        max_price_age: 0,
+        max_price_confidence_bps: 0,
+        next_college_id: MetaverfAccount::FIRST_COLLEGE_ID,
+    }
+}
+
+// Registered at 0 and paid 1000 in the payment mint for the first period
+fn registered_college(protocol: &MetaverfAccount) -> CollegeAccount {
+    let mut college = CollegeAccount::new(
+        MetaverfAccount::FIRST_COLLEGE_ID,
+        Pubkey::new_unique(),
+        None,
+        protocol,
+        0,
+        255,
+    );
+    college.record_payment(protocol.payment_mint, 1_000, 0).unwrap();
+    college
+}
+
+fn payment(mint: Pubkey, amount: u64) -> Payment {
+    Payment { mint, amount }
+}
+
+#[test]
+fn unused_payments_shrink_with_the_time_left() {
+    let usdc = Pubkey::new_unique();
+    let college = registered_college(&protocol(usdc));
+
+    assert_eq!(college.unused_payments(0), vec![payment(usdc, 1_000)]);
+    assert_eq!(college.unused_payments(250), vec![payment(usdc, 750)]);
+    assert!(college.unused_payments(1_000).is_empty());
+    assert!(college.unused_payments(5_000).is_empty());
+}
+
+#[test]
+fn paying_in_another_mint_keeps_the_earlier_payment_refundable() {
+    let usdc = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let mut college = registered_college(&protocol(usdc));
+
+    // A plan upgrade halfway through paid in another mint
+    college.record_payment(other, 300, 500).unwrap();
+
+    assert_eq!(college.paid_from, 500);
+    assert_eq!(college.payments, vec![payment(usdc, 500), payment(other, 300)]);
+    assert_eq!(college.unused_payments(750), vec![payment(usdc, 250), payment(other, 150)]);
+}
+
+#[test]
+fn renewals_in_the_same_mint_add_up() {
+    let usdc = Pubkey::new_unique();
+    let protocol = protocol(usdc);
+    let mut college = registered_college(&protocol);
+
+    college.record_payment(usdc, 1_000, 500).unwrap();
+    college.extend_subscription(&protocol, 1, 500).unwrap();
+
+    assert_eq!(college.paid_until, 2_000);
+    assert_eq!(college.payments, vec![payment(usdc, 1_500)]);
+    assert_eq!(college.unused_payments(1_250), vec![payment(usdc, 750)]);
+}
+
+#[test]
+fn stretched_time_is_covered_by_what_was_left() {
+    let usdc = Pubkey::new_unique();
+    let mut college = registered_college(&protocol(usdc));
+
+    // A downgrade halfway through to half the price doubles the time left
+    college.carry_payments(500);
+    college.paid_until = 1_500;
+
+    assert_eq!(college.payments, vec![payment(usdc, 500)]);
+    assert_eq!(college.unused_payments(1_000), vec![payment(usdc, 250)]);
+}
+
+#[test]
+fn payments_are_capped_per_mint() {
+    let usdc = Pubkey::new_unique();
+    let mut college = registered_college(&protocol(usdc));
+
+    for _ in 1..CollegeAccount::MAX_PAYMENT_MINTS {
+        college.record_payment(Pubkey::new_unique(), 100, 0).unwrap();
+    }
+    match college.record_payment(Pubkey::new_unique(), 100, 0) {
+        Err(Error::AnchorError(error)) => assert_eq!(
+            error.error_code_number,
+            u32::from(CertificateError::TooManyPaymentMints)
+        ),
+        result => panic!("expected TooManyPaymentMints, got {result:?}"),
+    }
+
+    // A mint already held still takes payments, and used up payments free their slot
+    college.record_payment(usdc, 100, 0).unwrap();
+    assert_eq!(college.payments[0], payment(usdc, 1_100));
+    college.paid_until = 2_000;
+    college.record_payment(Pubkey::new_unique(), 100, 2_000).unwrap();
+    assert_eq!(college.payments.len(), 1);
+}
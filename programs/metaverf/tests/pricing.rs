@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use metaverf::error::CertificateError;
+use metaverf::state::{AcceptedMint, MetaverfAccount, Plan, PrepayDiscount};
+
+// Six decimals like USDC, so the default annual fee is 10 tokens
+fn protocol(payment_mint: Pubkey) -> MetaverfAccount {
+    MetaverfAccount {
+        annual_fee: 10_000_000,
+        verf_bump: 254,
+        subscription_duration: 1_000,
+        admin: Pubkey::new_unique(),
+        pending_admin: None,
+        council: Vec::new(),
+        council_threshold: 0,
+        proposal_count: 0,
+        parameter_delay: 0,
+        paused: false,
+        payment_mint,
+        version: MetaverfAccount::VERSION,
+        payout_recipients: Vec::new(),
+        grace_period: 0,
+        prepay_discounts: Vec::new(),
+        certificate_fee: 0,
+        refund_bps: 0,
+        sol_price_feed: Pubkey::default(),
+        max_price_age: 0,
+        max_price_confidence_bps: 0,
+        next_college_id: MetaverfAccount::FIRST_COLLEGE_ID,
+    }
+}
+
+fn accepted_mint(annual_fee: u64) -> AcceptedMint {
+    AcceptedMint {
+        mint: Pubkey::new_unique(),
+        annual_fee,
+        pending_annual_fee: None,
+        fee_effective_at: 0,
+        enabled: true,
+        bump: 255,
+    }
+}
+
+fn plan(price: u64) -> Plan {
+    Plan {
+        id: 1,
+        name: "Basic".to_string(),
+        price,
+        pending_price: None,
+        price_effective_at: 0,
+        max_collections: 2,
+        yearly_certificate_quota: 100,
+        active: true,
+        bump: 255,
+    }
+}
+
+fn assert_error<T>(result: Result<T>, expected: CertificateError) {
+    match result {
+        Err(Error::AnchorError(error)) => assert_eq!(error.error_code_number, u32::from(expected)),
+        Err(error) => panic!("unexpected error {error:?}"),
+        Ok(_) => panic!("expected {expected:?}"),
+    }
+}
+
+#[test]
+fn plan_prices_scale_into_a_mint_with_more_decimals() {
+    let protocol = protocol(Pubkey::new_unique());
+    // The same 10 tokens at nine decimals
+    let accepted = accepted_mint(10_000_000_000);
+
+    let fee = protocol.annual_fee_in(&accepted.mint, Some(&accepted), Some(&plan(15_000_000))).unwrap();
+    assert_eq!(fee, 15_000_000_000);
+
+    // Without a plan the accepted mint's own fee applies unscaled
+    assert_eq!(protocol.annual_fee_in(&accepted.mint, Some(&accepted), None).unwrap(), 10_000_000_000);
+}
+
+#[test]
+fn plan_prices_scale_into_a_mint_with_fewer_decimals() {
+    let protocol = protocol(Pubkey::new_unique());
+    // The same 10 tokens at two decimals, anything below a hundredth is rounded down
+    let accepted = accepted_mint(1_000);
+
+    let fee = protocol.annual_fee_in(&accepted.mint, Some(&accepted), Some(&plan(15_009_999))).unwrap();
+    assert_eq!(fee, 1_500);
+}
+
+#[test]
+fn payment_mint_prices_are_not_scaled() {
+    let payment_mint = Pubkey::new_unique();
+    let protocol = protocol(payment_mint);
+
+    assert_eq!(protocol.annual_fee_in(&payment_mint, None, None).unwrap(), 10_000_000);
+    assert_eq!(protocol.annual_fee_in(&payment_mint, None, Some(&plan(15_000_000))).unwrap(), 15_000_000);
+}
+
+#[test]
+fn disabled_or_mismatched_accepted_mints_are_rejected() {
+    let protocol = protocol(Pubkey::new_unique());
+    let mut accepted = accepted_mint(1_000);
+
+    assert_error(
+        protocol.annual_fee_in(&Pubkey::new_unique(), Some(&accepted), None),
+        CertificateError::InvalidPaymentMint,
+    );
+    assert_error(protocol.annual_fee_in(&accepted.mint, None, None), CertificateError::InvalidPaymentMint);
+
+    accepted.enabled = false;
+    assert_error(
+        protocol.annual_fee_in(&accepted.mint, Some(&accepted), None),
+        CertificateError::InvalidPaymentMint,
+    );
+}
+
+#[test]
+fn scaling_a_plan_price_past_u64_overflows() {
+    let mut protocol = protocol(Pubkey::new_unique());
+    protocol.annual_fee = 1;
+    let accepted = accepted_mint(u64::MAX);
+
+    assert_error(
+        protocol.annual_fee_in(&accepted.mint, Some(&accepted), Some(&plan(2))),
+        CertificateError::MathOverflow,
+    );
+}
+
+fn discount(min_periods: u8, discount_bps: u16) -> PrepayDiscount {
+    PrepayDiscount { min_periods, discount_bps }
+}
+
+#[test]
+fn renewals_without_discounts_cost_the_fee_per_period() {
+    let protocol = protocol(Pubkey::new_unique());
+
+    assert_eq!(protocol.renewal_cost(10_000, 1).unwrap(), 10_000);
+    assert_eq!(protocol.renewal_cost(10_000, 3).unwrap(), 30_000);
+}
+
+#[test]
+fn renewals_get_the_best_discount_they_qualify_for() {
+    let mut protocol = protocol(Pubkey::new_unique());
+    protocol
+        .set_prepay_discounts(vec![discount(3, 500), discount(5, 1_500), discount(2, 200)])
+        .unwrap();
+
+    assert_eq!(protocol.renewal_cost(10_000, 1).unwrap(), 10_000);
+    assert_eq!(protocol.renewal_cost(10_000, 2).unwrap(), 19_600);
+    assert_eq!(protocol.renewal_cost(10_000, 4).unwrap(), 38_000);
+    assert_eq!(protocol.renewal_cost(10_000, 10).unwrap(), 85_000);
+
+    // A shorter commitment with a bigger discount wins over a longer one
+    protocol.set_prepay_discounts(vec![discount(2, 2_000), discount(5, 1_000)]).unwrap();
+    assert_eq!(protocol.renewal_cost(10_000, 5).unwrap(), 40_000);
+}
+
+#[test]
+fn renewal_periods_must_be_between_one_and_the_maximum() {
+    let protocol = protocol(Pubkey::new_unique());
+
+    assert_error(protocol.renewal_cost(10_000, 0), CertificateError::InvalidRenewalPeriods);
+    assert_error(
+        protocol.renewal_cost(10_000, MetaverfAccount::MAX_RENEWAL_PERIODS + 1),
+        CertificateError::InvalidRenewalPeriods,
+    );
+    assert_eq!(
+        protocol.renewal_cost(10_000, MetaverfAccount::MAX_RENEWAL_PERIODS).unwrap(),
+        100_000
+    );
+}
+
+#[test]
+fn invalid_prepay_discounts_are_rejected() {
+    let mut protocol = protocol(Pubkey::new_unique());
+    protocol.set_prepay_discounts(vec![discount(2, 500)]).unwrap();
+
+    assert_error(
+        protocol.set_prepay_discounts(vec![discount(0, 500)]),
+        CertificateError::InvalidPrepayDiscounts,
+    );
+    assert_error(
+        protocol.set_prepay_discounts(vec![discount(2, 10_000)]),
+        CertificateError::InvalidPrepayDiscounts,
+    );
+    assert_error(
+        protocol.set_prepay_discounts((1..=6).map(|periods| discount(periods, 100)).collect()),
+        CertificateError::InvalidPrepayDiscounts,
+    );
+    assert!(protocol.prepay_discounts == vec![discount(2, 500)]);
+
+    // An empty list turns the discounts off
+    protocol.set_prepay_discounts(Vec::new()).unwrap();
+    assert_eq!(protocol.renewal_cost(10_000, 2).unwrap(), 20_000);
+}
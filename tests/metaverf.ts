@@ -12,14 +12,17 @@ import {
 import {
   createMint,
   getAssociatedTokenAddressSync,
+  getAccount,
   getOrCreateAssociatedTokenAccount,
   mintTo,
+  transfer,
   ASSOCIATED_TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { MPL_CORE_PROGRAM_ID } from "@metaplex-foundation/mpl-core";
 import { BN } from "bn.js";
 import { createUmi } from "@metaplex-foundation/umi-bundle-defaults";
 import { mplCore } from "@metaplex-foundation/mpl-core";
+import { expect } from "chai";
 
 describe("metaverf", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
@@ -32,6 +35,16 @@ describe("metaverf", () => {
   // Constants
   const annualFee = new BN(1e4);
   const subscriptionDuration = new BN(1e6);
+  const parameterDelay = new BN(0);
+  const gracePeriod = new BN(1e5);
+  const firstCollegeId = new BN(65536); // Assigned IDs start above the u16 range
+  const collegeProfileArgs = {
+    name: "Test University",
+    countryCode: "IN",
+    website: "https://example.edu",
+    logoUri: "https://example.edu/logo.png",
+    accreditingBody: "NAAC",
+  };
   // Changed to 1 college only
   const totalColleges = 1;
 
@@ -81,13 +94,93 @@ describe("metaverf", () => {
     return signature;
   };
 
+  // Fails unless `promise` rejects with the program error `code`
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    let error: unknown;
+    try {
+      await promise;
+    } catch (err) {
+      error = err;
+    }
+    expect(error, `expected ${code}`).to.be.instanceOf(anchor.AnchorError);
+    expect((error as anchor.AnchorError).error.errorCode.code).to.equal(code);
+  };
+
+  // The mock SOL/USD feed in tests/fixtures/sol_usd_price.json quotes $150 at exponent -8, so a
+  // six-decimal fee converts at 10^(9 - 6 + 8) lamports per price unit
+  const feeInLamports = (fee: BN) => (BigInt(fee.toString()) * BigInt(1e11)) / BigInt(15e9);
+
+  const fund = async (to: PublicKey, lamports: number) => {
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: provider.publicKey,
+          toPubkey: to,
+          lamports,
+        })
+      )
+    );
+  };
+
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+  // Registers a college for `authority`, which must hold enough SOL for rent, and pays its fee
+  const registerCollegeFor = async (authority: Keypair, coupon: PublicKey | null = null) => {
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    const collegeId = protocol.nextCollegeId;
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), collegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [collegeLookup] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_lookup"), authority.publicKey.toBuffer()],
+      program.programId
+    );
+    const [collegeProfile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_profile"), collegeAccount.toBuffer()],
+      program.programId
+    );
+    const authorityTokenAccount = (await getOrCreateAssociatedTokenAccount(
+      connection,
+      admin,
+      mintUsdc,
+      authority.publicKey,
+      false
+    )).address;
+    await mintTo(connection, admin, mintUsdc, authorityTokenAccount, admin, 1000000);
+
+    await program.methods
+      .registerCollege(collegeProfileArgs)
+      .accountsPartial({
+        mintUsdc: mintUsdc,
+        acceptedMint: null,
+        plan: null,
+        coupon: coupon,
+        collegeAccount: collegeAccount,
+        collegeLookup: collegeLookup,
+        collegeProfile: collegeProfile,
+        collegeAuthority: authority.publicKey,
+        metaverfAccount: metaverfAccount,
+        treasury: treasury,
+        payerTokenAccount: authorityTokenAccount,
+        tokenProgram: tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc()
+      .then(confirm);
+
+    return { collegeId, collegeAccount, collegeLookup, authorityTokenAccount };
+  };
+
   before(async () => {
     // Initialize admin account
     admin = Keypair.generate();
     const transferIx = SystemProgram.transfer({
       fromPubkey: provider.publicKey,
       toPubkey: admin.publicKey,
-      lamports: 0.001 * LAMPORTS_PER_SOL,
+      lamports: 2 * LAMPORTS_PER_SOL,
     });
     const tx = new Transaction().add(transferIx);
     await provider.sendAndConfirm(tx);
@@ -97,7 +190,7 @@ describe("metaverf", () => {
     const authorityTransferIx = SystemProgram.transfer({
       fromPubkey: provider.publicKey,
       toPubkey: collegeAuthority.publicKey,
-      lamports: 0.001 * LAMPORTS_PER_SOL,
+      lamports: 2 * LAMPORTS_PER_SOL,
     });
     const authorityTx = new Transaction().add(authorityTransferIx);
     await provider.sendAndConfirm(authorityTx);
@@ -218,7 +311,7 @@ describe("metaverf", () => {
   it("Initialize Protocol", async () => {
     try {
       const tx = await program.methods
-        .initialize(annualFee, subscriptionDuration)
+        .initialize(annualFee, subscriptionDuration, parameterDelay, gracePeriod)
         .accountsPartial({
           admin: admin.publicKey,
           mintUsdc: mintUsdc,
@@ -245,20 +338,31 @@ describe("metaverf", () => {
 
   it("Register College", async () => {
     try {
-      const collegeId = 1;
-
-      // Derive PDA for this specific college ID
+      // The program assigns the ID, read the next one from the protocol
+      const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
       const [collegeAccount] = PublicKey.findProgramAddressSync(
-        [Buffer.from("college"), new Uint8Array([collegeId, 0])], // le bytes for u16
+        [Buffer.from("college"), protocol.nextCollegeId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [collegeLookup] = PublicKey.findProgramAddressSync(
+        [Buffer.from("college_lookup"), collegeAuthority.publicKey.toBuffer()],
+        program.programId
+      );
+      const [collegeProfile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("college_profile"), collegeAccount.toBuffer()],
         program.programId
       );
 
       const tx = await program.methods
-        .registerCollege(collegeId)
+        .registerCollege(collegeProfileArgs)
         .accountsPartial({
-          admin: admin.publicKey,
           mintUsdc: mintUsdc,
+          acceptedMint: null,
+          plan: null,
+          coupon: null,
           collegeAccount: collegeAccount,
+          collegeLookup: collegeLookup,
+          collegeProfile: collegeProfile,
           collegeAuthority: collegeAuthority.publicKey,
           metaverfAccount: metaverfAccount,
           treasury: treasury,
@@ -267,7 +371,7 @@ describe("metaverf", () => {
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
-        .signers([admin, collegeAuthority])
+        .signers([collegeAuthority])
         .rpc({ skipPreflight: true })
         .then(confirm)
         .then(log);
@@ -282,23 +386,51 @@ describe("metaverf", () => {
     }
   });
 
+  it("Look up two colleges by their authorities", async () => {
+    const authorities = [Keypair.generate(), Keypair.generate()];
+    const registered = [];
+    for (const authority of authorities) {
+      await fund(authority.publicKey, LAMPORTS_PER_SOL);
+      registered.push(await registerCollegeFor(authority));
+    }
+
+    // IDs are assigned in order and seed the college PDA with all eight bytes
+    expect(registered[1].collegeId.toString()).to.equal(registered[0].collegeId.addn(1).toString());
+
+    for (const [i, authority] of authorities.entries()) {
+      const [collegeLookup] = PublicKey.findProgramAddressSync(
+        [Buffer.from("college_lookup"), authority.publicKey.toBuffer()],
+        program.programId
+      );
+      const lookup = await program.account.collegeLookup.fetch(collegeLookup);
+      expect(lookup.college.toBase58()).to.equal(registered[i].collegeAccount.toBase58());
+      expect(lookup.collegeId.toString()).to.equal(registered[i].collegeId.toString());
+
+      const college = await program.account.collegeAccount.fetch(lookup.college);
+      expect(college.authority.toBase58()).to.equal(authority.publicKey.toBase58());
+      expect(college.collegeId.toString()).to.equal(registered[i].collegeId.toString());
+    }
+  });
+
   it("Renew Subscription College", async () => {
     try {
-      const collegeId = 1;
+      const collegeId = firstCollegeId;
 
       // Use the same PDA derivation as in registration
       const [collegeAccount] = PublicKey.findProgramAddressSync(
-        [Buffer.from("college"), new Uint8Array([collegeId, 0])],
+        [Buffer.from("college"), collegeId.toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
       const tx = await program.methods
-        .renewSubscription(collegeId)
+        .renewSubscription(collegeId, 1)
         .accountsPartial({
-          admin: admin.publicKey,
           mintUsdc: mintUsdc,
+          acceptedMint: null,
+          plan: null,
+          coupon: null,
           collegeAccount: collegeAccount,
-          collegeAuthority: collegeAuthority.publicKey,
+          payer: collegeAuthority.publicKey,
           metaverfAccount: metaverfAccount,
           treasury: treasury,
           payerTokenAccount: payerTokenAccount,
@@ -306,7 +438,7 @@ describe("metaverf", () => {
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
-        .signers([admin, collegeAuthority])
+        .signers([collegeAuthority])
         .rpc()
         .then(confirm)
         .then(log);
@@ -327,11 +459,13 @@ describe("metaverf", () => {
   //     const tx = await program.methods
   //       .withdrawFees(amount)
   //       .accountsPartial({
-  //         admin: admin.publicKey,
+  //         authority: admin.publicKey,
+  //         treasurer: null,
   //         mintUsdc: mintUsdc,
+  //         acceptedMint: null,
   //         metaverfAccount: metaverfAccount,
   //         treasury: treasury,
-  //         adminTokenAccount,
+  //         authorityTokenAccount: adminTokenAccount,
   //         tokenProgram: tokenProgram,
   //         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
   //         systemProgram: SystemProgram.programId,
@@ -347,12 +481,130 @@ describe("metaverf", () => {
   //   }
   // });
 
+  it("Renew rejects a mint that is neither the payment mint nor accepted", async () => {
+    const collegeId = firstCollegeId;
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), collegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    const wrongMint = await createMint(connection, admin, admin.publicKey, null, 6);
+    const wrongTreasury = (await getOrCreateAssociatedTokenAccount(
+      connection,
+      admin,
+      wrongMint,
+      metaverfAccount,
+      true
+    )).address;
+    const wrongPayerTokenAccount = (await getOrCreateAssociatedTokenAccount(
+      connection,
+      admin,
+      wrongMint,
+      collegeAuthority.publicKey,
+      false
+    )).address;
+    await mintTo(connection, admin, wrongMint, wrongPayerTokenAccount, admin, 1000000);
+
+    const before = await program.account.collegeAccount.fetch(collegeAccount);
+
+    await expectError(
+      program.methods
+        .renewSubscription(collegeId, 1)
+        .accountsPartial({
+          mintUsdc: wrongMint,
+          acceptedMint: null,
+          plan: null,
+          coupon: null,
+          collegeAccount: collegeAccount,
+          payer: collegeAuthority.publicKey,
+          metaverfAccount: metaverfAccount,
+          treasury: wrongTreasury,
+          payerTokenAccount: wrongPayerTokenAccount,
+          tokenProgram: tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([collegeAuthority])
+        .rpc(),
+      "InvalidPaymentMint"
+    );
+
+    const after = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(after.paidUntil.toString()).to.equal(before.paidUntil.toString());
+    const payer = await getAccount(connection, wrongPayerTokenAccount);
+    expect(payer.amount.toString()).to.equal("1000000");
+  });
+
+  it("A sponsor can renew a college but not take it over", async () => {
+    const collegeId = firstCollegeId;
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), collegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    const sponsor = Keypair.generate();
+    await fund(sponsor.publicKey, LAMPORTS_PER_SOL);
+    const sponsorTokenAccount = (await getOrCreateAssociatedTokenAccount(
+      connection,
+      admin,
+      mintUsdc,
+      sponsor.publicKey,
+      false
+    )).address;
+    await mintTo(connection, admin, mintUsdc, sponsorTokenAccount, admin, 1000000);
+
+    const before = await program.account.collegeAccount.fetch(collegeAccount);
+
+    await program.methods
+      .renewSubscription(collegeId, 1)
+      .accountsPartial({
+        mintUsdc: mintUsdc,
+        acceptedMint: null,
+        plan: null,
+        coupon: null,
+        collegeAccount: collegeAccount,
+        payer: sponsor.publicKey,
+        metaverfAccount: metaverfAccount,
+        treasury: treasury,
+        payerTokenAccount: sponsorTokenAccount,
+        tokenProgram: tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([sponsor])
+      .rpc()
+      .then(confirm);
+
+    // Paying extends the subscription and leaves the college in its authority's hands
+    const after = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(after.paidUntil.toString()).to.equal(before.paidUntil.add(subscriptionDuration).toString());
+    expect(after.authority.toBase58()).to.equal(collegeAuthority.publicKey.toBase58());
+    expect(after.pendingAuthority).to.equal(null);
+
+    await expectError(
+      program.methods
+        .proposeCollegeAuthority(collegeId, sponsor.publicKey)
+        .accountsPartial({
+          collegeAuthority: sponsor.publicKey,
+          collegeAccount: collegeAccount,
+        })
+        .signers([sponsor])
+        .rpc(),
+      "NotAuthorized"
+    );
+  });
+
   it("Add collection1 to college", async () => {
     try {
-      const collegeId = 1;
+      const collegeId = firstCollegeId;
 
       const [collegeAccount] = PublicKey.findProgramAddressSync(
-        [Buffer.from("college"), new Uint8Array([collegeId, 0])],
+        [Buffer.from("college"), collegeId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      const [collegeProfile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("college_profile"), collegeAccount.toBuffer()],
         program.programId
       );
 
@@ -368,7 +620,10 @@ describe("metaverf", () => {
         .addCollection(collegeId, args)
         .accountsStrict({
           collegeAccount: collegeAccount,
+          collegeProfile: collegeProfile,
           collegeAuthority: collegeAuthority.publicKey,
+          metaverfAccount: metaverfAccount,
+          plan: null,
           mplCoreProgram: MPL_CORE_PROGRAM_ID,
           newCollection: collection1.publicKey,
           systemProgram: SystemProgram.programId,
@@ -412,6 +667,7 @@ describe("metaverf", () => {
   //       .accountsStrict({
   //         collegeAccount: collegeAccount,
   //         collegeAuthority: collegeAuthority.publicKey,
+  //         metaverfAccount: metaverfAccount,
   //         mplCoreProgram: MPL_CORE_PROGRAM_ID,
   //         newCollection: collection2.publicKey,
   //         systemProgram: SystemProgram.programId,
@@ -435,10 +691,10 @@ describe("metaverf", () => {
 
   it("Minting Certificates for Student1 under collection1", async () => {
     try {
-      const collegeId = 1;
+      const collegeId = firstCollegeId;
 
       const [collegeAccount] = PublicKey.findProgramAddressSync(
-        [Buffer.from("college"), new Uint8Array([collegeId, 0])],
+        [Buffer.from("college"), collegeId.toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
@@ -461,7 +717,14 @@ describe("metaverf", () => {
         .mintCertificate(collegeId, args)
         .accountsStrict({
           collegeAccount: collegeAccount,
-          collegeAuthority: collegeAuthority.publicKey,
+          issuer: collegeAuthority.publicKey,
+          issuerDelegate: null,
+          metaverfAccount: metaverfAccount,
+          plan: null,
+          mintUsdc: null,
+          treasury: null,
+          payerTokenAccount: null,
+          tokenProgram: null,
           mplCoreProgram: MPL_CORE_PROGRAM_ID,
           collection: collection1.publicKey,
           systemProgram: SystemProgram.programId,
@@ -492,10 +755,10 @@ describe("metaverf", () => {
 
   it("Minting Certificates for Student2 under Collection1", async () => {
     try {
-      const collegeId = 1;
+      const collegeId = firstCollegeId;
 
       const [collegeAccount] = PublicKey.findProgramAddressSync(
-        [Buffer.from("college"), new Uint8Array([collegeId, 0])],
+        [Buffer.from("college"), collegeId.toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
@@ -518,7 +781,14 @@ describe("metaverf", () => {
         .mintCertificate(collegeId, args)
         .accountsStrict({
           collegeAccount: collegeAccount,
-          collegeAuthority: collegeAuthority.publicKey,
+          issuer: collegeAuthority.publicKey,
+          issuerDelegate: null,
+          metaverfAccount: metaverfAccount,
+          plan: null,
+          mintUsdc: null,
+          treasury: null,
+          payerTokenAccount: null,
+          tokenProgram: null,
           mplCoreProgram: MPL_CORE_PROGRAM_ID,
           collection: collection1.publicKey,
           systemProgram: SystemProgram.programId,
@@ -550,10 +820,10 @@ describe("metaverf", () => {
 
   it("Minting Certificates for Student3 under collection1", async () => {
     try {
-      const collegeId = 1;
+      const collegeId = firstCollegeId;
 
       const [collegeAccount] = PublicKey.findProgramAddressSync(
-        [Buffer.from("college"), new Uint8Array([collegeId, 0])],
+        [Buffer.from("college"), collegeId.toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
@@ -576,7 +846,14 @@ describe("metaverf", () => {
         .mintCertificate(collegeId, args)
         .accountsStrict({
           collegeAccount: collegeAccount,
-          collegeAuthority: collegeAuthority.publicKey,
+          issuer: collegeAuthority.publicKey,
+          issuerDelegate: null,
+          metaverfAccount: metaverfAccount,
+          plan: null,
+          mintUsdc: null,
+          treasury: null,
+          payerTokenAccount: null,
+          tokenProgram: null,
           mplCoreProgram: MPL_CORE_PROGRAM_ID,
           collection: collection1.publicKey,
           systemProgram: SystemProgram.programId,
@@ -605,6 +882,82 @@ describe("metaverf", () => {
     }
   });
 
+  it("Charge the certificate fee on every mint once it is set", async () => {
+    const certificateFee = new BN(500);
+    const setCertificateFee = async (fee: BN) => {
+      await program.methods
+        .updateParameters(null, null, null, fee)
+        .accountsPartial({
+          authority: admin.publicKey,
+          feeManager: null,
+          metaverfAccount: metaverfAccount,
+        })
+        .signers([admin])
+        .rpc()
+        .then(confirm);
+      await program.methods
+        .applyParameters()
+        .accountsPartial({
+          metaverfAccount: metaverfAccount,
+        })
+        .rpc()
+        .then(confirm);
+    };
+    await setCertificateFee(certificateFee);
+
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), firstCollegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const args = {
+      name: "TEST ASSET",
+      uri: "https://example.com/event",
+      studentName: "STUDENT NAME",
+      courseName: "Turbine",
+      completionDate: "15 feb",
+      grade: "1st year",
+    };
+    const mint = (withFeeAccounts: boolean) => {
+      const asset = Keypair.generate();
+      const studentWallet = Keypair.generate();
+      return program.methods
+        .mintCertificate(firstCollegeId, args)
+        .accountsStrict({
+          collegeAccount: collegeAccount,
+          issuer: collegeAuthority.publicKey,
+          issuerDelegate: null,
+          metaverfAccount: metaverfAccount,
+          plan: null,
+          mintUsdc: withFeeAccounts ? mintUsdc : null,
+          treasury: withFeeAccounts ? treasury : null,
+          payerTokenAccount: withFeeAccounts ? payerTokenAccount : null,
+          tokenProgram: withFeeAccounts ? tokenProgram : null,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          collection: collection1.publicKey,
+          systemProgram: SystemProgram.programId,
+          asset: asset.publicKey,
+          studentWallet: studentWallet.publicKey,
+        })
+        .signers([collegeAuthority, asset, studentWallet])
+        .rpc();
+    };
+
+    await expectError(mint(false), "MissingFeeAccounts");
+
+    const treasuryBefore = (await getAccount(connection, treasury)).amount;
+    const payerBefore = (await getAccount(connection, payerTokenAccount)).amount;
+    const issuedBefore = (await program.account.collegeAccount.fetch(collegeAccount)).certificatesIssued;
+
+    await mint(true).then(confirm);
+
+    const fee = BigInt(certificateFee.toString());
+    expect((await getAccount(connection, treasury)).amount - treasuryBefore).to.equal(fee);
+    expect(payerBefore - (await getAccount(connection, payerTokenAccount)).amount).to.equal(fee);
+    const issuedAfter = (await program.account.collegeAccount.fetch(collegeAccount)).certificatesIssued;
+    expect(issuedAfter.toString()).to.equal(issuedBefore.addn(1).toString());
+
+    await setCertificateFee(new BN(0));
+  });
 
   // it("Withdraw Fees 2nd time", async () => {
   //   try {
@@ -612,11 +965,13 @@ describe("metaverf", () => {
   //     const tx = await program.methods
   //       .withdrawFees(amount)
   //       .accountsPartial({
-  //         admin: admin.publicKey,
+  //         authority: admin.publicKey,
+  //         treasurer: null,
   //         mintUsdc: mintUsdc,
+  //         acceptedMint: null,
   //         metaverfAccount: metaverfAccount,
   //         treasury: treasury,
-  //         adminTokenAccount,
+  //         authorityTokenAccount: adminTokenAccount,
   //         tokenProgram: tokenProgram,
   //         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
   //         systemProgram: SystemProgram.programId,
@@ -661,6 +1016,7 @@ describe("metaverf", () => {
   //       .accountsStrict({
   //         collegeAccount: collegeAccount,
   //         collegeAuthority: collegeAuthority.publicKey,
+  //         metaverfAccount: metaverfAccount,
   //         mplCoreProgram: MPL_CORE_PROGRAM_ID,
   //         collection: collection2.publicKey,
   //         systemProgram: SystemProgram.programId,
@@ -718,6 +1074,7 @@ describe("metaverf", () => {
   //       .accountsStrict({
   //         collegeAccount: collegeAccount,
   //         collegeAuthority: collegeAuthority.publicKey,
+  //         metaverfAccount: metaverfAccount,
   //         mplCoreProgram: MPL_CORE_PROGRAM_ID,
   //         collection: collection2.publicKey,
   //         systemProgram: SystemProgram.programId,
@@ -776,6 +1133,7 @@ describe("metaverf", () => {
   //       .accountsStrict({
   //         collegeAccount: collegeAccount,
   //         collegeAuthority: collegeAuthority.publicKey,
+  //         metaverfAccount: metaverfAccount,
   //         mplCoreProgram: MPL_CORE_PROGRAM_ID,
   //         collection: collection2.publicKey,
   //         systemProgram: SystemProgram.programId,
@@ -805,14 +1163,45 @@ describe("metaverf", () => {
   // });
 
 
+  it("Update Parameters rejects a non-admin signer", async () => {
+    await expectError(
+      program.methods
+        .updateParameters(new BN(1), null, null, null)
+        .accountsPartial({
+          authority: collegeAuthority.publicKey,
+          feeManager: null,
+          metaverfAccount: metaverfAccount,
+        })
+        .signers([collegeAuthority])
+        .rpc(),
+      "MissingRole"
+    );
+  });
+
+  it("Update Parameters rejects a zero subscription duration", async () => {
+    await expectError(
+      program.methods
+        .updateParameters(null, new BN(0), null, null)
+        .accountsPartial({
+          authority: admin.publicKey,
+          feeManager: null,
+          metaverfAccount: metaverfAccount,
+        })
+        .signers([admin])
+        .rpc(),
+      "InvalidSubscriptionDuration"
+    );
+  });
+
   it("Update Parameters", async () => {
     try {
       const newAnnualFee = new BN(2e6);
       const newSubscriptionDuration = new BN(2e6);
       const tx = await program.methods
-        .updateParameters(newAnnualFee, newSubscriptionDuration)
+        .updateParameters(newAnnualFee, newSubscriptionDuration, null, null)
         .accountsPartial({
-          admin: admin.publicKey,
+          authority: admin.publicKey,
+          feeManager: null,
           metaverfAccount: metaverfAccount,
         })
         .signers([admin])
@@ -826,75 +1215,2143 @@ describe("metaverf", () => {
     }
   });
 
-  it("Withdraw Fees last time", async () => {
-    try {
-      const amount = new BN(10); // Withdraw all fees
-      const tx = await program.methods
-        .withdrawFees(amount)
+  it("Apply Parameters", async () => {
+    await program.methods
+      .applyParameters()
+      .accountsPartial({
+        metaverfAccount: metaverfAccount,
+      })
+      .rpc()
+      .then(confirm);
+
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    expect(protocol.annualFee.toString()).to.equal(new BN(2e6).toString());
+    expect(protocol.subscriptionDuration.toString()).to.equal(new BN(2e6).toString());
+  });
+
+  it("Propose and cancel admin transfer", async () => {
+    const newAdmin = Keypair.generate();
+
+    await program.methods
+      .proposeAdmin(newAdmin.publicKey)
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    await program.methods
+      .cancelAdminTransfer()
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    expect(protocol.pendingAdmin).to.equal(null);
+
+    // A cancelled transfer cannot be accepted
+    await expectError(
+      program.methods
+        .acceptAdmin()
+        .accountsPartial({
+          newAdmin: newAdmin.publicKey,
+          metaverfAccount: metaverfAccount,
+        })
+        .signers([newAdmin])
+        .rpc(),
+      "NoPendingAdmin"
+    );
+  });
+
+  it("Accept admin transfer only from the proposed key", async () => {
+    const newAdmin = Keypair.generate();
+    const stranger = Keypair.generate();
+
+    await program.methods
+      .proposeAdmin(newAdmin.publicKey)
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    await expectError(
+      program.methods
+        .acceptAdmin()
+        .accountsPartial({
+          newAdmin: stranger.publicKey,
+          metaverfAccount: metaverfAccount,
+        })
+        .signers([stranger])
+        .rpc(),
+      "UnauthorizedPendingAdmin"
+    );
+
+    await program.methods
+      .acceptAdmin()
+      .accountsPartial({
+        newAdmin: newAdmin.publicKey,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([newAdmin])
+      .rpc()
+      .then(confirm);
+
+    let protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    expect(protocol.admin.toBase58()).to.equal(newAdmin.publicKey.toBase58());
+
+    // Hand the protocol back so the remaining tests keep their admin
+    await program.methods
+      .proposeAdmin(admin.publicKey)
+      .accountsPartial({
+        admin: newAdmin.publicKey,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([newAdmin])
+      .rpc()
+      .then(confirm);
+    await program.methods
+      .acceptAdmin()
+      .accountsPartial({
+        newAdmin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    expect(protocol.admin.toBase58()).to.equal(admin.publicKey.toBase58());
+    expect(protocol.pendingAdmin).to.equal(null);
+  });
+
+  it("Grant and revoke a fee manager role", async () => {
+    const feeManager = Keypair.generate();
+    const [roleAssignment] = PublicKey.findProgramAddressSync(
+      [Buffer.from("role"), Buffer.from("fee_manager"), feeManager.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .grantRole({ feeManager: {} }, feeManager.publicKey)
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+        roleAssignment: roleAssignment,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    const assignment = await program.account.roleAssignment.fetch(roleAssignment);
+    expect(assignment.holder.toBase58()).to.equal(feeManager.publicKey.toBase58());
+
+    await program.methods
+      .revokeRole()
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+        roleAssignment: roleAssignment,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    expect(await program.account.roleAssignment.fetchNullable(roleAssignment)).to.equal(null);
+  });
+
+  it("Pause and unpause protocol", async () => {
+    await program.methods
+      .pauseProtocol()
+      .accountsPartial({
+        authority: admin.publicKey,
+        pauser: null,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    let protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    expect(protocol.paused).to.equal(true);
+
+    await program.methods
+      .unpauseProtocol()
+      .accountsPartial({
+        authority: admin.publicKey,
+        pauser: null,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    expect(protocol.paused).to.equal(false);
+  });
+
+  it("Create, reprice and apply a plan", async () => {
+    const planId = 1;
+    const [plan] = PublicKey.findProgramAddressSync(
+      [Buffer.from("plan"), new Uint8Array([planId])],
+      program.programId
+    );
+
+    await program.methods
+      .createPlan(planId, "Basic", new BN(1e6), 2, 100)
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+        plan: plan,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    await program.methods
+      .updatePlan(new BN(15e5), null, null, null)
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+        plan: plan,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    await program.methods
+      .applyPlanPrice()
+      .accountsPartial({
+        plan: plan,
+      })
+      .rpc()
+      .then(confirm);
+
+    const planAccount = await program.account.plan.fetch(plan);
+    expect(planAccount.price.toString()).to.equal(new BN(15e5).toString());
+  });
+
+  it("Accept a second mint and pay and withdraw fees in it", async () => {
+    // Nine decimals against the payment mint's six, the accepted mint is priced on its own
+    const secondMint = await createMint(connection, admin, admin.publicKey, null, 9);
+    const [acceptedMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("accepted_mint"), secondMint.toBuffer()],
+      program.programId
+    );
+    const secondTreasury = getAssociatedTokenAddressSync(secondMint, metaverfAccount, true);
+
+    await program.methods
+      .addAcceptedMint(new BN(5e9))
+      .accountsPartial({
+        admin: admin.publicKey,
+        mint: secondMint,
+        metaverfAccount: metaverfAccount,
+        acceptedMint: acceptedMint,
+        treasury: secondTreasury,
+        tokenProgram: tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    // The payment mint cannot be listed a second time
+    await expectError(
+      program.methods
+        .addAcceptedMint(new BN(5e9))
         .accountsPartial({
           admin: admin.publicKey,
-          mintUsdc: mintUsdc,
+          mint: mintUsdc,
           metaverfAccount: metaverfAccount,
           treasury: treasury,
-          adminTokenAccount,
           tokenProgram: tokenProgram,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([admin])
+        .rpc(),
+      "InvalidPaymentMint"
+    );
+
+    const updateAcceptedMint = (annualFee: BN | null, enabled: boolean | null) =>
+      program.methods
+        .updateAcceptedMint(annualFee, enabled)
+        .accountsPartial({
+          admin: admin.publicKey,
+          metaverfAccount: metaverfAccount,
+          acceptedMint: acceptedMint,
+        })
+        .signers([admin])
         .rpc()
-        .then(confirm)
-        .then(log);
+        .then(confirm);
+
+    // Fee changes are queued and only take effect once applied
+    await updateAcceptedMint(new BN(8e9), null);
+    let accepted = await program.account.acceptedMint.fetch(acceptedMint);
+    expect(accepted.annualFee.toString()).to.equal(new BN(5e9).toString());
+    expect(accepted.pendingAnnualFee.toString()).to.equal(new BN(8e9).toString());
+
+    await program.methods
+      .applyAcceptedMintFee()
+      .accountsPartial({
+        acceptedMint: acceptedMint,
+      })
+      .rpc()
+      .then(confirm);
+
+    accepted = await program.account.acceptedMint.fetch(acceptedMint);
+    expect(accepted.annualFee.toString()).to.equal(new BN(8e9).toString());
+    expect(accepted.pendingAnnualFee).to.equal(null);
+    expect(accepted.enabled).to.equal(true);
+
+    // Register and renew a college paying in the second mint
+    const authority = Keypair.generate();
+    await fund(authority.publicKey, LAMPORTS_PER_SOL);
+    const authorityTokenAccount = (await getOrCreateAssociatedTokenAccount(
+      connection,
+      admin,
+      secondMint,
+      authority.publicKey,
+      false
+    )).address;
+    await mintTo(connection, admin, secondMint, authorityTokenAccount, admin, 100e9);
 
-      console.log("Withdraw success:", tx);
-    } catch (error) {
-      console.log(error);
-    }
-  });
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    const collegeId = protocol.nextCollegeId;
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), collegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [collegeLookup] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_lookup"), authority.publicKey.toBuffer()],
+      program.programId
+    );
+    const [collegeProfile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_profile"), collegeAccount.toBuffer()],
+      program.programId
+    );
 
+    await program.methods
+      .registerCollege(collegeProfileArgs)
+      .accountsPartial({
+        mintUsdc: secondMint,
+        acceptedMint: acceptedMint,
+        plan: null,
+        coupon: null,
+        collegeAccount: collegeAccount,
+        collegeLookup: collegeLookup,
+        collegeProfile: collegeProfile,
+        collegeAuthority: authority.publicKey,
+        metaverfAccount: metaverfAccount,
+        treasury: secondTreasury,
+        payerTokenAccount: authorityTokenAccount,
+        tokenProgram: tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc()
+      .then(confirm);
+
+    const college = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(college.payments.length).to.equal(1);
+    expect(college.payments[0].mint.toBase58()).to.equal(secondMint.toBase58());
+    expect((await getAccount(connection, secondTreasury)).amount).to.equal(BigInt(8e9));
+
+    const renew = () =>
+      program.methods
+        .renewSubscription(collegeId, 1)
+        .accountsPartial({
+          mintUsdc: secondMint,
+          acceptedMint: acceptedMint,
+          plan: null,
+          coupon: null,
+          collegeAccount: collegeAccount,
+          payer: authority.publicKey,
+          metaverfAccount: metaverfAccount,
+          treasury: secondTreasury,
+          payerTokenAccount: authorityTokenAccount,
+          tokenProgram: tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
 
+    await renew().then(confirm);
 
+    const renewed = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(renewed.paidUntil.toString()).to.equal(
+      college.paidUntil.add(protocol.subscriptionDuration).toString()
+    );
+    expect((await getAccount(connection, secondTreasury)).amount).to.equal(BigInt(16e9));
 
-//TODO PARAMETERS ARE NOT CHANGED ITS AN HECTIC TASK probably will use AI
+    // The admin withdraws the second mint's treasury into its own account for that mint
+    const adminSecondTokenAccount = (await getOrCreateAssociatedTokenAccount(
+      connection,
+      admin,
+      secondMint,
+      admin.publicKey,
+      false
+    )).address;
 
-  // it("Register College", async () => {
-  //   try {
-  //     const collegeId = 1;
+    await program.methods
+      .withdrawFees(new BN(16e9))
+      .accountsPartial({
+        authority: admin.publicKey,
+        treasurer: null,
+        mintUsdc: secondMint,
+        acceptedMint: acceptedMint,
+        metaverfAccount: metaverfAccount,
+        treasury: secondTreasury,
+        authorityTokenAccount: adminSecondTokenAccount,
+        tokenProgram: tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
 
-  //     // Derive PDA for this specific college ID
-  //     const [collegeAccount] = PublicKey.findProgramAddressSync(
-  //       [Buffer.from("college"), new Uint8Array([collegeId, 0])], // le bytes for u16
-  //       program.programId
-  //     );
+    expect((await getAccount(connection, secondTreasury)).amount).to.equal(BigInt(0));
+    expect((await getAccount(connection, adminSecondTokenAccount)).amount).to.equal(BigInt(16e9));
 
-  //     const tx = await program.methods
-  //       .registerCollege(collegeId)
-  //       .accountsPartial({
-  //         admin: admin.publicKey,
-  //         mintUsdc: mintUsdc,
-  //         collegeAccount: collegeAccount,
-  //         collegeAuthority: collegeAuthority.publicKey,
-  //         metaverfAccount: metaverfAccount,
-  //         treasury: treasury,
-  //         payerTokenAccount: payerTokenAccount,
-  //         tokenProgram: tokenProgram,
-  //         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-  //         systemProgram: SystemProgram.programId,
-  //       })
-  //       .signers([admin, collegeAuthority])
-  //       .rpc({ skipPreflight: true })
-  //       .then(confirm)
-  //       .then(log);
+    // A disabled mint no longer pays fees
+    await updateAcceptedMint(null, false);
+    await expectError(renew(), "InvalidPaymentMint");
+  });
 
-  //     console.log(`Register College signature:`, tx);
-  //   } catch (error) {
-  //     if (error instanceof anchor.web3.SendTransactionError) {
-  //       console.log("Detailed error:", error.logs);
-  //     } else {
-  //       console.log(error);
-  //     }
-  //   }
-  // });
+  it("Update college profile", async () => {
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), firstCollegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [collegeProfile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_profile"), collegeAccount.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .updateCollegeProfile({ ...collegeProfileArgs, website: "https://university.example.edu" })
+      .accountsPartial({
+        collegeAuthority: collegeAuthority.publicKey,
+        collegeAccount: collegeAccount,
+        collegeProfile: collegeProfile,
+      })
+      .signers([collegeAuthority])
+      .rpc()
+      .then(confirm);
+
+    const profile = await program.account.collegeProfile.fetch(collegeProfile);
+    expect(profile.website).to.equal("https://university.example.edu");
+  });
+
+  it("Propose and cancel college authority transfer", async () => {
+    const newAuthority = Keypair.generate();
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), firstCollegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .proposeCollegeAuthority(firstCollegeId, newAuthority.publicKey)
+      .accountsPartial({
+        collegeAuthority: collegeAuthority.publicKey,
+        collegeAccount: collegeAccount,
+      })
+      .signers([collegeAuthority])
+      .rpc()
+      .then(confirm);
+
+    await program.methods
+      .cancelCollegeAuthorityTransfer(firstCollegeId)
+      .accountsPartial({
+        collegeAuthority: collegeAuthority.publicKey,
+        collegeAccount: collegeAccount,
+      })
+      .signers([collegeAuthority])
+      .rpc()
+      .then(confirm);
+
+    const college = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(college.pendingAuthority).to.equal(null);
+  });
+
+  it("Set college update authority", async () => {
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), firstCollegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const setUpdateAuthority = (updateAuthority: PublicKey) =>
+      program.methods
+        .setCollegeUpdateAuthority(firstCollegeId, updateAuthority)
+        .accountsPartial({
+          collegeAuthority: collegeAuthority.publicKey,
+          collegeAccount: collegeAccount,
+        })
+        .signers([collegeAuthority])
+        .rpc()
+        .then(confirm);
+    const updateCollection = (signer: Keypair, uri: string) =>
+      program.methods
+        .updateCollection(firstCollegeId, { name: null, uri: uri })
+        .accountsPartial({
+          updateAuthority: signer.publicKey,
+          collegeAccount: collegeAccount,
+          metaverfAccount: metaverfAccount,
+          collection: collection1.publicKey,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+
+    // A separate key, e.g. a registrar's, takes over collection metadata from the college authority
+    const registrar = Keypair.generate();
+    await fund(registrar.publicKey, LAMPORTS_PER_SOL);
+    await setUpdateAuthority(registrar.publicKey);
+
+    let college = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(college.updateAuthority.toBase58()).to.equal(registrar.publicKey.toBase58());
+
+    const uri = "https://example.edu/collection1-v2.json";
+    await updateCollection(registrar, uri).then(confirm);
+    college = await program.account.collegeAccount.fetch(collegeAccount);
+    const info = college.collections.find((c) => c.collection.equals(collection1.publicKey));
+    expect(info.uri).to.equal(uri);
+
+    await expectError(updateCollection(collegeAuthority, "https://example.edu/stale.json"), "NotAuthorized");
+
+    // Hand metadata back to the college authority for the tests that follow
+    await setUpdateAuthority(collegeAuthority.publicKey);
+  });
+
+  it("Create and revoke an issuer delegate", async () => {
+    const staff = Keypair.generate();
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), firstCollegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [issuerDelegate] = PublicKey.findProgramAddressSync(
+      [Buffer.from("issuer_delegate"), collegeAccount.toBuffer(), staff.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .createIssuerDelegate(firstCollegeId, {
+        delegate: staff.publicKey,
+        collections: [collection1.publicKey],
+        expiresAt: new BN(Math.floor(Date.now() / 1000) + 30 * 24 * 60 * 60),
+        dailyCap: 50,
+      })
+      .accountsPartial({
+        collegeAuthority: collegeAuthority.publicKey,
+        collegeAccount: collegeAccount,
+        issuerDelegate: issuerDelegate,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([collegeAuthority])
+      .rpc()
+      .then(confirm);
+
+    const delegate = await program.account.issuerDelegate.fetch(issuerDelegate);
+    expect(delegate.dailyCap).to.equal(50);
+    expect(delegate.grantedBy.toBase58()).to.equal(collegeAuthority.publicKey.toBase58());
+
+    await program.methods
+      .revokeIssuerDelegate(firstCollegeId)
+      .accountsPartial({
+        collegeAuthority: collegeAuthority.publicKey,
+        collegeAccount: collegeAccount,
+        issuerDelegate: issuerDelegate,
+      })
+      .signers([collegeAuthority])
+      .rpc()
+      .then(confirm);
+
+    expect(await program.account.issuerDelegate.fetchNullable(issuerDelegate)).to.equal(null);
+  });
+
+  it("Register an accreditor, attest and revoke accreditation", async () => {
+    const accreditorAuthority = Keypair.generate();
+    await fund(accreditorAuthority.publicKey, 0.01 * LAMPORTS_PER_SOL);
+
+    const programme = "BSc Computer Science";
+    const [accreditor] = PublicKey.findProgramAddressSync(
+      [Buffer.from("accreditor"), accreditorAuthority.publicKey.toBuffer()],
+      program.programId
+    );
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), firstCollegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [accreditation] = PublicKey.findProgramAddressSync(
+      [Buffer.from("accreditation"), accreditor.toBuffer(), collegeAccount.toBuffer(), Buffer.from(programme)],
+      program.programId
+    );
+
+    await program.methods
+      .registerAccreditor(accreditorAuthority.publicKey, "National Accreditation Board")
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+        accreditor: accreditor,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    await program.methods
+      .attestAccreditation(firstCollegeId, programme, new BN(0))
+      .accountsPartial({
+        accreditorAuthority: accreditorAuthority.publicKey,
+        accreditor: accreditor,
+        collegeAccount: collegeAccount,
+        accreditation: accreditation,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([accreditorAuthority])
+      .rpc()
+      .then(confirm);
+
+    let record = await program.account.accreditation.fetch(accreditation);
+    expect(record.status).to.deep.equal({ accredited: {} });
+
+    await program.methods
+      .revokeAccreditation(firstCollegeId)
+      .accountsPartial({
+        accreditorAuthority: accreditorAuthority.publicKey,
+        accreditor: accreditor,
+        collegeAccount: collegeAccount,
+        accreditation: accreditation,
+      })
+      .signers([accreditorAuthority])
+      .rpc()
+      .then(confirm);
+
+    record = await program.account.accreditation.fetch(accreditation);
+    expect(record.status).to.deep.equal({ revoked: {} });
+  });
+
+  it("Expire lapsed accreditations and revoke those of a deactivated accreditor", async () => {
+    const accreditorAuthority = Keypair.generate();
+    await fund(accreditorAuthority.publicKey, 0.05 * LAMPORTS_PER_SOL);
+    const [accreditor] = PublicKey.findProgramAddressSync(
+      [Buffer.from("accreditor"), accreditorAuthority.publicKey.toBuffer()],
+      program.programId
+    );
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), firstCollegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const accreditationFor = (programme: string) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("accreditation"), accreditor.toBuffer(), collegeAccount.toBuffer(), Buffer.from(programme)],
+        program.programId
+      )[0];
+
+    await program.methods
+      .registerAccreditor(accreditorAuthority.publicKey, "Regional Accreditation Council")
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+        accreditor: accreditor,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    const attest = (programme: string, expiresAt: BN) =>
+      program.methods
+        .attestAccreditation(firstCollegeId, programme, expiresAt)
+        .accountsPartial({
+          accreditorAuthority: accreditorAuthority.publicKey,
+          accreditor: accreditor,
+          collegeAccount: collegeAccount,
+          accreditation: accreditationFor(programme),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([accreditorAuthority])
+        .rpc();
+    const expire = (programme: string) =>
+      program.methods
+        .expireAccreditation(firstCollegeId)
+        .accountsPartial({
+          collegeAccount: collegeAccount,
+          accreditor: accreditor,
+          accreditation: accreditationFor(programme),
+        })
+        .rpc();
+    const setActive = (signer: Keypair, active: boolean) =>
+      program.methods
+        .setAccreditorActive(active)
+        .accountsPartial({
+          admin: signer.publicKey,
+          metaverfAccount: metaverfAccount,
+          accreditor: accreditor,
+        })
+        .signers([signer])
+        .rpc();
+
+    const shortLived = "MSc Data Science";
+    const standing = "BSc Mathematics";
+    const now = await connection.getBlockTime(await connection.getSlot());
+    await attest(shortLived, new BN(now + 3)).then(confirm);
+    await attest(standing, new BN(0)).then(confirm);
+
+    // Valid attestations cannot be marked
+    await expectError(expire(shortLived), "AccreditationStillValid");
+    await expectError(expire(standing), "AccreditationStillValid");
+
+    await sleep(5000);
+    await expire(shortLived).then(confirm);
+    expect((await program.account.accreditation.fetch(accreditationFor(shortLived))).status).to.deep.equal({
+      expired: {},
+    });
+    await expectError(expire(shortLived), "AccreditationNotActive");
+
+    // Only the admin can deactivate an accreditor, which then cannot attest
+    await expectError(setActive(accreditorAuthority, false), "UnauthorizedAdmin");
+    await setActive(admin, false).then(confirm);
+    expect((await program.account.accreditor.fetch(accreditor)).active).to.equal(false);
+    await expectError(attest("BA Economics", new BN(0)), "AccreditorNotActive");
+
+    // Its standing attestations no longer count and anyone can revoke them
+    await expire(standing).then(confirm);
+    let record = await program.account.accreditation.fetch(accreditationFor(standing));
+    expect(record.status).to.deep.equal({ revoked: {} });
+    expect(record.revokedAt.toNumber()).to.be.greaterThan(0);
+
+    // Reactivating lets the accreditor attest again but does not restore what was revoked
+    await setActive(admin, true).then(confirm);
+    expect((await program.account.accreditor.fetch(accreditor)).active).to.equal(true);
+    record = await program.account.accreditation.fetch(accreditationFor(standing));
+    expect(record.status).to.deep.equal({ revoked: {} });
+  });
+
+  it("Renew with SOL priced by the mock oracle", async () => {
+    const collegeId = firstCollegeId;
+    const priceFeed = new PublicKey("5yJnZeBGU9vHfTKrcus8CBfsYWApJyDpSmhg948Corc6");
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), collegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [solVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sol_vault")],
+      program.programId
+    );
+
+    // The fixture's publish time is fixed, so accept any age here
+    await program.methods
+      .setSolPriceFeed(priceFeed, new BN(1e10), 100)
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    const before = await program.account.collegeAccount.fetch(collegeAccount);
+    const vaultBefore = await connection.getBalance(solVault);
+
+    // Any wallet can sponsor a renewal without the college signing, here the admin pays
+    await program.methods
+      .renewSubscriptionWithSol(collegeId, 1)
+      .accountsPartial({
+        payer: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+        mintUsdc: mintUsdc,
+        priceFeed: priceFeed,
+        solVault: solVault,
+        collegeAccount: collegeAccount,
+        plan: null,
+        coupon: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    const after = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(after.paidUntil.gt(before.paidUntil)).to.equal(true);
+    expect(BigInt(await connection.getBalance(solVault)) - BigInt(vaultBefore)).to.equal(
+      feeInLamports((await program.account.metaverfAccount.fetch(metaverfAccount)).annualFee)
+    );
+  });
+
+  it("Register with SOL and withdraw SOL fees", async () => {
+    const priceFeed = new PublicKey("5yJnZeBGU9vHfTKrcus8CBfsYWApJyDpSmhg948Corc6");
+    const [solVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sol_vault")],
+      program.programId
+    );
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    const collegeId = protocol.nextCollegeId;
+    const authority = Keypair.generate();
+    await fund(authority.publicKey, LAMPORTS_PER_SOL);
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), collegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [collegeLookup] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_lookup"), authority.publicKey.toBuffer()],
+      program.programId
+    );
+    const [collegeProfile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_profile"), collegeAccount.toBuffer()],
+      program.programId
+    );
+
+    const vaultBefore = BigInt(await connection.getBalance(solVault));
+    await program.methods
+      .registerCollegeWithSol(collegeProfileArgs)
+      .accountsPartial({
+        collegeAuthority: authority.publicKey,
+        metaverfAccount: metaverfAccount,
+        mintUsdc: mintUsdc,
+        priceFeed: priceFeed,
+        solVault: solVault,
+        plan: null,
+        coupon: null,
+        collegeAccount: collegeAccount,
+        collegeLookup: collegeLookup,
+        collegeProfile: collegeProfile,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc()
+      .then(confirm);
+
+    const lamports = feeInLamports(protocol.annualFee);
+    expect(BigInt(await connection.getBalance(solVault)) - vaultBefore).to.equal(lamports);
+    const college = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(college.collegeId.toString()).to.equal(collegeId.toString());
+    expect(college.active).to.equal(true);
+    // SOL payments are recorded under the default key
+    expect(college.payments.length).to.equal(1);
+    expect(college.payments[0].mint.toBase58()).to.equal(PublicKey.default.toBase58());
+    expect(college.payments[0].amount.toString()).to.equal(lamports.toString());
+
+    const withdrawSol = (signer: Keypair, amount: BN) =>
+      program.methods
+        .withdrawSol(amount)
+        .accountsPartial({
+          authority: signer.publicKey,
+          treasurer: null,
+          metaverfAccount: metaverfAccount,
+          solVault: solVault,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+
+    await expectError(withdrawSol(authority, new BN(lamports.toString())), "MissingRole");
+
+    const vaultBeforeWithdraw = BigInt(await connection.getBalance(solVault));
+    await withdrawSol(admin, new BN(lamports.toString())).then(confirm);
+    expect(vaultBeforeWithdraw - BigInt(await connection.getBalance(solVault))).to.equal(lamports);
+  });
+
+  it("Create and withdraw a coupon", async () => {
+    const code = "PILOT2025";
+    const [coupon] = PublicKey.findProgramAddressSync(
+      [Buffer.from("coupon"), Buffer.from(code)],
+      program.programId
+    );
+
+    await program.methods
+      .createCoupon(code, { percent: { bps: 2500 } }, 10, new BN(0), [firstCollegeId])
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+        coupon: coupon,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    await program.methods
+      .setCouponActive(false)
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+        coupon: coupon,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    const couponAccount = await program.account.coupon.fetch(coupon);
+    expect(couponAccount.active).to.equal(false);
+  });
+
+  it("Redeem percent and fixed coupons on registration and renewal", async () => {
+    const createCoupon = async (
+      code: string,
+      discount: { percent: { bps: number } } | { fixed: { amount: BN } },
+      maxUses: number,
+      expiresAt: BN,
+      allowedColleges: BN[]
+    ) => {
+      const [coupon] = PublicKey.findProgramAddressSync(
+        [Buffer.from("coupon"), Buffer.from(code)],
+        program.programId
+      );
+      await program.methods
+        .createCoupon(code, discount, maxUses, expiresAt, allowedColleges)
+        .accountsPartial({
+          admin: admin.publicKey,
+          metaverfAccount: metaverfAccount,
+          coupon: coupon,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc()
+        .then(confirm);
+      return coupon;
+    };
+
+    const fee = BigInt((await program.account.metaverfAccount.fetch(metaverfAccount)).annualFee.toString());
+    const fixedOff = new BN(5e5);
+    const percentCoupon = await createCoupon("HALFOFF", { percent: { bps: 5000 } }, 2, new BN(0), []);
+    const fixedCoupon = await createCoupon("FLATOFF", { fixed: { amount: fixedOff } }, 0, new BN(0), []);
+    // Expiry 0 means never, so any past timestamp will do
+    const expiredCoupon = await createCoupon("EXPIRED", { percent: { bps: 5000 } }, 0, new BN(1), []);
+    const firstCollegeCoupon = await createCoupon("FIRSTONLY", { percent: { bps: 5000 } }, 0, new BN(0), [
+      firstCollegeId,
+    ]);
+
+    // Registers a new college with `coupon` and returns what it paid
+    const register = async (coupon: PublicKey) => {
+      const authority = Keypair.generate();
+      await fund(authority.publicKey, LAMPORTS_PER_SOL);
+      // Enough to pay the full fee, the helper alone mints less than that
+      const tokenAccount = (await getOrCreateAssociatedTokenAccount(
+        connection,
+        admin,
+        mintUsdc,
+        authority.publicKey,
+        false
+      )).address;
+      await mintTo(connection, admin, mintUsdc, tokenAccount, admin, 1e7);
+      const treasuryBefore = (await getAccount(connection, treasury)).amount;
+      const registered = await registerCollegeFor(authority, coupon);
+      const paid = (await getAccount(connection, treasury)).amount - treasuryBefore;
+      return { authority, paid, ...registered };
+    };
+
+    const renew = async (
+      college: { authority: Keypair; collegeId: BN; collegeAccount: PublicKey; authorityTokenAccount: PublicKey },
+      coupon: PublicKey
+    ) => {
+      const balanceBefore = (await getAccount(connection, college.authorityTokenAccount)).amount;
+      await program.methods
+        .renewSubscription(college.collegeId, 1)
+        .accountsPartial({
+          mintUsdc: mintUsdc,
+          acceptedMint: null,
+          plan: null,
+          coupon: coupon,
+          collegeAccount: college.collegeAccount,
+          payer: college.authority.publicKey,
+          metaverfAccount: metaverfAccount,
+          treasury: treasury,
+          payerTokenAccount: college.authorityTokenAccount,
+          tokenProgram: tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([college.authority])
+        .rpc();
+      return balanceBefore - (await getAccount(connection, college.authorityTokenAccount)).amount;
+    };
+
+    // A percent coupon takes its share off, until its uses run out
+    const percentCollege = await register(percentCoupon);
+    expect(percentCollege.paid).to.equal(fee / BigInt(2));
+    expect(await renew(percentCollege, percentCoupon)).to.equal(fee / BigInt(2));
+    expect((await program.account.coupon.fetch(percentCoupon)).uses).to.equal(2);
+    await expectError(renew(percentCollege, percentCoupon), "CouponExhausted");
+
+    // A fixed coupon takes its amount off, with no cap on uses
+    const fixedCollege = await register(fixedCoupon);
+    expect(fixedCollege.paid).to.equal(fee - BigInt(fixedOff.toString()));
+    expect(await renew(fixedCollege, fixedCoupon)).to.equal(fee - BigInt(fixedOff.toString()));
+
+    await expectError(renew(fixedCollege, expiredCoupon), "CouponExpired");
+    // Only the colleges on a coupon's allowlist can redeem it
+    await expectError(renew(fixedCollege, firstCollegeCoupon), "InvalidCoupon");
+  });
+
+  it("Set refund policy", async () => {
+    await program.methods
+      .setRefundPolicy(5000)
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    expect(protocol.refundBps).to.equal(5000);
+
+    await expectError(
+      program.methods
+        .setRefundPolicy(10001)
+        .accountsPartial({
+          admin: admin.publicKey,
+          metaverfAccount: metaverfAccount,
+        })
+        .signers([admin])
+        .rpc(),
+      "InvalidRefundPolicy"
+    );
+  });
+
+  it("Council proposals need the threshold and only count members", async () => {
+    const members = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    const outsider = Keypair.generate();
+    for (const key of [...members, outsider]) {
+      await fund(key.publicKey, 0.05 * LAMPORTS_PER_SOL);
+    }
+    const [pendingParameters] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_parameters")],
+      program.programId
+    );
+    const proposalPda = (id: BN) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), id.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+    const executeAccounts = (proposal: PublicKey, proposer: PublicKey) => ({
+      metaverfAccount: metaverfAccount,
+      proposal: proposal,
+      proposer: proposer,
+      pendingParameters: pendingParameters,
+      acceptedMint: null,
+      plan: null,
+      coupon: null,
+      solVault: null,
+      solDestination: null,
+      systemProgram: null,
+    });
+
+    await program.methods
+      .setCouncil(members.map((m) => m.publicKey), 2)
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    // The admin can no longer act alone
+    await expectError(
+      program.methods
+        .setRefundPolicy(2500)
+        .accountsPartial({
+          admin: admin.publicKey,
+          metaverfAccount: metaverfAccount,
+        })
+        .signers([admin])
+        .rpc(),
+      "CouncilModeActive"
+    );
+
+    let protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    await expectError(
+      program.methods
+        .createProposal({ setRefundPolicy: { refundBps: 2500 } })
+        .accountsPartial({
+          proposer: outsider.publicKey,
+          metaverfAccount: metaverfAccount,
+          proposal: proposalPda(protocol.proposalCount),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([outsider])
+        .rpc(),
+      "NotCouncilMember"
+    );
+
+    const refundProposal = proposalPda(protocol.proposalCount);
+    await program.methods
+      .createProposal({ setRefundPolicy: { refundBps: 2500 } })
+      .accountsPartial({
+        proposer: members[0].publicKey,
+        metaverfAccount: metaverfAccount,
+        proposal: refundProposal,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([members[0]])
+      .rpc()
+      .then(confirm);
+
+    // One approval, the proposer's own, is below the threshold of two
+    await expectError(
+      program.methods
+        .executeProposal()
+        .accountsPartial(executeAccounts(refundProposal, members[0].publicKey))
+        .rpc(),
+      "ProposalThresholdNotMet"
+    );
+
+    await expectError(
+      program.methods
+        .approveProposal()
+        .accountsPartial({
+          member: outsider.publicKey,
+          metaverfAccount: metaverfAccount,
+          proposal: refundProposal,
+        })
+        .signers([outsider])
+        .rpc(),
+      "NotCouncilMember"
+    );
+
+    await expectError(
+      program.methods
+        .approveProposal()
+        .accountsPartial({
+          member: members[0].publicKey,
+          metaverfAccount: metaverfAccount,
+          proposal: refundProposal,
+        })
+        .signers([members[0]])
+        .rpc(),
+      "ProposalAlreadyApproved"
+    );
+
+    await program.methods
+      .approveProposal()
+      .accountsPartial({
+        member: members[1].publicKey,
+        metaverfAccount: metaverfAccount,
+        proposal: refundProposal,
+      })
+      .signers([members[1]])
+      .rpc()
+      .then(confirm);
+
+    await program.methods
+      .executeProposal()
+      .accountsPartial(executeAccounts(refundProposal, members[0].publicKey))
+      .rpc()
+      .then(confirm);
+
+    protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    expect(protocol.refundBps).to.equal(2500);
+    expect(await program.account.councilProposal.fetchNullable(refundProposal)).to.equal(null);
+
+    // Turn council mode off again through the council
+    const disbandProposal = proposalPda(protocol.proposalCount);
+    await program.methods
+      .createProposal({ setCouncil: { members: [], threshold: 0 } })
+      .accountsPartial({
+        proposer: members[1].publicKey,
+        metaverfAccount: metaverfAccount,
+        proposal: disbandProposal,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([members[1]])
+      .rpc()
+      .then(confirm);
+    await program.methods
+      .approveProposal()
+      .accountsPartial({
+        member: members[2].publicKey,
+        metaverfAccount: metaverfAccount,
+        proposal: disbandProposal,
+      })
+      .signers([members[2]])
+      .rpc()
+      .then(confirm);
+    await program.methods
+      .executeProposal()
+      .accountsPartial(executeAccounts(disbandProposal, members[1].publicKey))
+      .rpc()
+      .then(confirm);
+
+    protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    expect(protocol.councilThreshold).to.equal(0);
+    expect(protocol.council).to.deep.equal([]);
+  });
+
+  it("Expire a college only once it lapses past the grace period", async () => {
+    // Two-second subscriptions without grace, so colleges registered from here on lapse quickly
+    await program.methods
+      .updateParameters(annualFee, new BN(2), new BN(0), null)
+      .accountsPartial({
+        authority: admin.publicKey,
+        feeManager: null,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+    await program.methods
+      .applyParameters()
+      .accountsPartial({
+        metaverfAccount: metaverfAccount,
+      })
+      .rpc()
+      .then(confirm);
+
+    const authority = Keypair.generate();
+    await fund(authority.publicKey, LAMPORTS_PER_SOL);
+    const { collegeId, collegeAccount } = await registerCollegeFor(authority);
+
+    const expire = () =>
+      program.methods
+        .expireCollege(collegeId)
+        .accountsPartial({
+          metaverfAccount: metaverfAccount,
+          collegeAccount: collegeAccount,
+        })
+        .rpc();
+
+    await expectError(expire(), "SubscriptionNotLapsed");
+
+    await sleep(5000);
+    await expire().then(confirm);
+
+    const college = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(college.active).to.equal(false);
+    expect(college.deactivationReason).to.deep.equal({ expired: {} });
+    expect(college.deactivatedAt.toNumber()).to.be.greaterThan(0);
+
+    await expectError(expire(), "CollegeNotActive");
+  });
+
+  it("Close a college with a refund priced from what it paid", async () => {
+    await program.methods
+      .updateParameters(null, subscriptionDuration, null, null)
+      .accountsPartial({
+        authority: admin.publicKey,
+        feeManager: null,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+    await program.methods
+      .applyParameters()
+      .accountsPartial({
+        metaverfAccount: metaverfAccount,
+      })
+      .rpc()
+      .then(confirm);
+
+    const authority = Keypair.generate();
+    await fund(authority.publicKey, LAMPORTS_PER_SOL);
+    const { collegeId, collegeAccount, collegeLookup, authorityTokenAccount } =
+      await registerCollegeFor(authority);
+
+    const college = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(college.payments.length).to.equal(1);
+    expect(college.payments[0].mint.toBase58()).to.equal(mintUsdc.toBase58());
+    expect(college.payments[0].amount.toString()).to.equal(annualFee.toString());
+
+    const [collegeProfile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_profile"), collegeAccount.toBuffer()],
+      program.programId
+    );
+    const [tombstone] = PublicKey.findProgramAddressSync(
+      [Buffer.from("tombstone"), collegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    const before = (await getAccount(connection, authorityTokenAccount)).amount;
+
+    await program.methods
+      .closeCollege()
+      .accountsPartial({
+        collegeAuthority: authority.publicKey,
+        collegeAccount: collegeAccount,
+        collegeLookup: collegeLookup,
+        collegeProfile: collegeProfile,
+        tombstone: tombstone,
+        metaverfAccount: metaverfAccount,
+        plan: null,
+        solVault: null,
+        tokenProgram: tokenProgram,
+        systemProgram: SystemProgram.programId,
+      })
+      // The mint, treasury and college token account for each token payment
+      .remainingAccounts([
+        { pubkey: mintUsdc, isWritable: false, isSigner: false },
+        { pubkey: treasury, isWritable: true, isSigner: false },
+        { pubkey: authorityTokenAccount, isWritable: true, isSigner: false },
+      ])
+      .signers([authority])
+      .rpc()
+      .then(confirm);
+
+    // Almost the whole period is left, so the refund is just under the policy's share of the fee
+    const refund = (await getAccount(connection, authorityTokenAccount)).amount - before;
+    const maxRefund = (BigInt(annualFee.toString()) * BigInt(protocol.refundBps)) / BigInt(10000);
+    expect(refund > BigInt(0)).to.equal(true);
+    expect(refund <= maxRefund).to.equal(true);
+    expect(refund >= maxRefund - BigInt(1)).to.equal(true);
+    expect(await connection.getAccountInfo(collegeAccount)).to.equal(null);
+  });
+
+  it("Refund time a sponsor paid for to the college authority on close", async () => {
+    const authority = Keypair.generate();
+    await fund(authority.publicKey, LAMPORTS_PER_SOL);
+    const { collegeId, collegeAccount, collegeLookup, authorityTokenAccount } =
+      await registerCollegeFor(authority);
+
+    const sponsor = Keypair.generate();
+    await fund(sponsor.publicKey, LAMPORTS_PER_SOL);
+    const sponsorTokenAccount = (await getOrCreateAssociatedTokenAccount(
+      connection,
+      admin,
+      mintUsdc,
+      sponsor.publicKey,
+      false
+    )).address;
+    await mintTo(connection, admin, mintUsdc, sponsorTokenAccount, admin, 1000000);
+
+    await program.methods
+      .renewSubscription(collegeId, 1)
+      .accountsPartial({
+        mintUsdc: mintUsdc,
+        acceptedMint: null,
+        plan: null,
+        coupon: null,
+        collegeAccount: collegeAccount,
+        payer: sponsor.publicKey,
+        metaverfAccount: metaverfAccount,
+        treasury: treasury,
+        payerTokenAccount: sponsorTokenAccount,
+        tokenProgram: tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([sponsor])
+      .rpc()
+      .then(confirm);
+
+    const [collegeProfile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_profile"), collegeAccount.toBuffer()],
+      program.programId
+    );
+    const [tombstone] = PublicKey.findProgramAddressSync(
+      [Buffer.from("tombstone"), collegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    const authorityBefore = (await getAccount(connection, authorityTokenAccount)).amount;
+    const sponsorBefore = (await getAccount(connection, sponsorTokenAccount)).amount;
+
+    await program.methods
+      .closeCollege()
+      .accountsPartial({
+        collegeAuthority: authority.publicKey,
+        collegeAccount: collegeAccount,
+        collegeLookup: collegeLookup,
+        collegeProfile: collegeProfile,
+        tombstone: tombstone,
+        metaverfAccount: metaverfAccount,
+        plan: null,
+        solVault: null,
+        tokenProgram: tokenProgram,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        { pubkey: mintUsdc, isWritable: false, isSigner: false },
+        { pubkey: treasury, isWritable: true, isSigner: false },
+        { pubkey: authorityTokenAccount, isWritable: true, isSigner: false },
+      ])
+      .signers([authority])
+      .rpc()
+      .then(confirm);
+
+    // The refund covers both periods, more than the authority paid itself, and the sponsor gets nothing
+    const refund = (await getAccount(connection, authorityTokenAccount)).amount - authorityBefore;
+    const periodShare = (BigInt(annualFee.toString()) * BigInt(protocol.refundBps)) / BigInt(10000);
+    expect(refund > periodShare).to.equal(true);
+    expect(refund <= periodShare * BigInt(2)).to.equal(true);
+    expect((await getAccount(connection, sponsorTokenAccount)).amount).to.equal(sponsorBefore);
+  });
+
+  it("Auto renew a due college from its fee vault without its signature", async () => {
+    await program.methods
+      .updateParameters(null, new BN(3), new BN(0), null)
+      .accountsPartial({
+        authority: admin.publicKey,
+        feeManager: null,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+    await program.methods
+      .applyParameters()
+      .accountsPartial({
+        metaverfAccount: metaverfAccount,
+      })
+      .rpc()
+      .then(confirm);
+
+    const authority = Keypair.generate();
+    await fund(authority.publicKey, LAMPORTS_PER_SOL);
+    const { collegeAccount, authorityTokenAccount } = await registerCollegeFor(authority);
+    const [feeVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_vault"), collegeAccount.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .openFeeVault()
+      .accountsPartial({
+        collegeAuthority: authority.publicKey,
+        collegeAccount: collegeAccount,
+        metaverfAccount: metaverfAccount,
+        mintUsdc: mintUsdc,
+        acceptedMint: null,
+        feeVault: feeVault,
+        tokenProgram: tokenProgram,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc()
+      .then(confirm);
+
+    // Anyone can fund the vault with a plain token transfer
+    await transfer(
+      connection,
+      authority,
+      authorityTokenAccount,
+      feeVault,
+      authority,
+      BigInt(annualFee.toString()) * BigInt(2)
+    );
+
+    // Signed only by the provider wallet, which pays the transaction fee
+    const autoRenew = () =>
+      program.methods
+        .autoRenew()
+        .accountsPartial({
+          collegeAccount: collegeAccount,
+          metaverfAccount: metaverfAccount,
+          plan: null,
+          mintUsdc: mintUsdc,
+          acceptedMint: null,
+          feeVault: feeVault,
+          treasury: treasury,
+          tokenProgram: tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+    await expectError(autoRenew(), "RenewalNotDue");
+
+    await sleep(5000);
+    const before = await program.account.collegeAccount.fetch(collegeAccount);
+    const vaultBefore = (await getAccount(connection, feeVault)).amount;
+
+    await autoRenew().then(confirm);
+
+    const after = await program.account.collegeAccount.fetch(collegeAccount);
+    const vaultAfter = (await getAccount(connection, feeVault)).amount;
+    expect(after.paidUntil.gt(before.paidUntil)).to.equal(true);
+    expect(after.active).to.equal(true);
+    expect(vaultBefore - vaultAfter).to.equal(BigInt(annualFee.toString()));
+  });
+
+  it("Accept a college authority transfer and move the lookup", async () => {
+    const oldAuthority = Keypair.generate();
+    const newAuthority = Keypair.generate();
+    await fund(oldAuthority.publicKey, LAMPORTS_PER_SOL);
+    await fund(newAuthority.publicKey, LAMPORTS_PER_SOL);
+    const { collegeId, collegeAccount, collegeLookup } = await registerCollegeFor(oldAuthority);
+    const [newLookup] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_lookup"), newAuthority.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .proposeCollegeAuthority(collegeId, newAuthority.publicKey)
+      .accountsPartial({
+        collegeAuthority: oldAuthority.publicKey,
+        collegeAccount: collegeAccount,
+      })
+      .signers([oldAuthority])
+      .rpc()
+      .then(confirm);
+
+    const accept = (oldLookup: PublicKey) =>
+      program.methods
+        .acceptCollegeAuthority(collegeId)
+        .accountsPartial({
+          newAuthority: newAuthority.publicKey,
+          collegeAccount: collegeAccount,
+          oldAuthority: oldAuthority.publicKey,
+          oldLookup: oldLookup,
+          newLookup: newLookup,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([newAuthority])
+        .rpc();
+
+    // The old lookup is derived from the old authority and cannot be swapped for another account
+    await expectError(accept(newLookup), "ConstraintSeeds");
+    await accept(collegeLookup).then(confirm);
+
+    const college = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(college.authority.toBase58()).to.equal(newAuthority.publicKey.toBase58());
+    expect(college.pendingAuthority).to.equal(null);
+    expect(await connection.getAccountInfo(collegeLookup)).to.equal(null);
+    const lookup = await program.account.collegeLookup.fetch(newLookup);
+    expect(lookup.college.toBase58()).to.equal(collegeAccount.toBase58());
+  });
+
+  it("Suspend and reinstate a college as a college moderator", async () => {
+    const moderator = Keypair.generate();
+    const stranger = Keypair.generate();
+    const [roleAssignment] = PublicKey.findProgramAddressSync(
+      [Buffer.from("role"), Buffer.from("college_moderator"), moderator.publicKey.toBuffer()],
+      program.programId
+    );
+    const authority = Keypair.generate();
+    await fund(authority.publicKey, LAMPORTS_PER_SOL);
+    const { collegeId, collegeAccount, authorityTokenAccount } = await registerCollegeFor(authority);
+
+    await program.methods
+      .grantRole({ collegeModerator: {} }, moderator.publicKey)
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+        roleAssignment: roleAssignment,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    const setActive = (signer: Keypair, collegeModerator: PublicKey | null, active: boolean) =>
+      program.methods
+        .setCollegeActive(collegeId, active)
+        .accountsPartial({
+          authority: signer.publicKey,
+          collegeModerator: collegeModerator,
+          metaverfAccount: metaverfAccount,
+          collegeAccount: collegeAccount,
+        })
+        .signers([signer])
+        .rpc();
+
+    // Neither a key without the role nor one passing someone else's assignment may suspend
+    await expectError(setActive(stranger, null, false), "MissingRole");
+    await expectError(setActive(stranger, roleAssignment, false), "ConstraintSeeds");
+
+    await setActive(moderator, roleAssignment, false).then(confirm);
+
+    let college = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(college.active).to.equal(false);
+    expect(college.deactivationReason).to.deep.equal({ suspended: {} });
+    expect(college.deactivatedAt.toNumber()).to.be.greaterThan(0);
+
+    // Paying does not lift a suspension
+    await program.methods
+      .renewSubscription(collegeId, 1)
+      .accountsPartial({
+        mintUsdc: mintUsdc,
+        acceptedMint: null,
+        plan: null,
+        coupon: null,
+        collegeAccount: collegeAccount,
+        payer: authority.publicKey,
+        metaverfAccount: metaverfAccount,
+        treasury: treasury,
+        payerTokenAccount: authorityTokenAccount,
+        tokenProgram: tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc()
+      .then(confirm);
+
+    college = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(college.active).to.equal(false);
+
+    await setActive(moderator, roleAssignment, true).then(confirm);
+
+    college = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(college.active).to.equal(true);
+    expect(college.deactivationReason).to.deep.equal({ none: {} });
+    expect(college.deactivatedAt.toNumber()).to.equal(0);
+
+    // Once revoked, the former moderator is turned away
+    await program.methods
+      .revokeRole()
+      .accountsPartial({
+        admin: admin.publicKey,
+        metaverfAccount: metaverfAccount,
+        roleAssignment: roleAssignment,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+
+    await expectError(setActive(moderator, null, false), "MissingRole");
+  });
+
+  it("Renew several periods at once with the best prepay discount", async () => {
+    const setPrepayDiscounts = (signer: Keypair, discounts: { minPeriods: number; discountBps: number }[]) =>
+      program.methods
+        .setPrepayDiscounts(discounts)
+        .accountsPartial({
+          admin: signer.publicKey,
+          metaverfAccount: metaverfAccount,
+        })
+        .signers([signer])
+        .rpc();
+
+    await expectError(
+      setPrepayDiscounts(collegeAuthority, [{ minPeriods: 2, discountBps: 1000 }]),
+      "UnauthorizedAdmin"
+    );
+    await expectError(
+      setPrepayDiscounts(admin, [{ minPeriods: 0, discountBps: 1000 }]),
+      "InvalidPrepayDiscounts"
+    );
+    await setPrepayDiscounts(admin, [
+      { minPeriods: 2, discountBps: 1000 },
+      { minPeriods: 4, discountBps: 2500 },
+    ]).then(confirm);
+
+    const authority = Keypair.generate();
+    await fund(authority.publicKey, LAMPORTS_PER_SOL);
+    const { collegeId, collegeAccount, authorityTokenAccount } = await registerCollegeFor(authority);
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+
+    const renew = (periods: number) =>
+      program.methods
+        .renewSubscription(collegeId, periods)
+        .accountsPartial({
+          mintUsdc: mintUsdc,
+          acceptedMint: null,
+          plan: null,
+          coupon: null,
+          collegeAccount: collegeAccount,
+          payer: authority.publicKey,
+          metaverfAccount: metaverfAccount,
+          treasury: treasury,
+          payerTokenAccount: authorityTokenAccount,
+          tokenProgram: tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+    await expectError(renew(0), "InvalidRenewalPeriods");
+    await expectError(renew(11), "InvalidRenewalPeriods");
+
+    const before = await program.account.collegeAccount.fetch(collegeAccount);
+    const balanceBefore = (await getAccount(connection, authorityTokenAccount)).amount;
+
+    // Four periods qualify for both tiers and get the larger 25% off
+    await renew(4).then(confirm);
+
+    const balanceAfter = (await getAccount(connection, authorityTokenAccount)).amount;
+    const fourPeriods = BigInt(protocol.annualFee.toString()) * BigInt(4);
+    expect(balanceBefore - balanceAfter).to.equal((fourPeriods * BigInt(7500)) / BigInt(10000));
+    const after = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(after.paidUntil.gte(before.paidUntil.add(protocol.subscriptionDuration.muln(4)))).to.equal(true);
+
+    await setPrepayDiscounts(admin, []).then(confirm);
+  });
+
+  it("Change plan with prorated upgrades and stretched downgrades", async () => {
+    await program.methods
+      .updateParameters(null, subscriptionDuration, null, null)
+      .accountsPartial({
+        authority: admin.publicKey,
+        feeManager: null,
+        metaverfAccount: metaverfAccount,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+    await program.methods
+      .applyParameters()
+      .accountsPartial({
+        metaverfAccount: metaverfAccount,
+      })
+      .rpc()
+      .then(confirm);
+
+    const authority = Keypair.generate();
+    await fund(authority.publicKey, LAMPORTS_PER_SOL);
+    const { collegeAccount, authorityTokenAccount } = await registerCollegeFor(authority);
+    await mintTo(connection, admin, mintUsdc, authorityTokenAccount, admin, 2e6);
+
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    const duration = BigInt(protocol.subscriptionDuration.toString());
+    const defaultFee = BigInt(protocol.annualFee.toString());
+    const [plan] = PublicKey.findProgramAddressSync(
+      [Buffer.from("plan"), new Uint8Array([1])],
+      program.programId
+    );
+    const planFee = BigInt((await program.account.plan.fetch(plan)).price.toString());
+
+    // Same fee as the payment mint, so plan prices carry over unscaled
+    const otherMint = await createMint(connection, admin, admin.publicKey, null, 6);
+    const [acceptedMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("accepted_mint"), otherMint.toBuffer()],
+      program.programId
+    );
+    const otherTreasury = getAssociatedTokenAddressSync(otherMint, metaverfAccount, true);
+    await program.methods
+      .addAcceptedMint(protocol.annualFee)
+      .accountsPartial({
+        admin: admin.publicKey,
+        mint: otherMint,
+        metaverfAccount: metaverfAccount,
+        acceptedMint: acceptedMint,
+        treasury: otherTreasury,
+        tokenProgram: tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc()
+      .then(confirm);
+    const otherTokenAccount = (await getOrCreateAssociatedTokenAccount(
+      connection,
+      admin,
+      otherMint,
+      authority.publicKey,
+      false
+    )).address;
+    await mintTo(connection, admin, otherMint, otherTokenAccount, admin, 1e9);
+
+    const changePlan = (
+      currentPlan: PublicKey | null,
+      newPlan: PublicKey | null,
+      mint: PublicKey,
+      mintTreasury: PublicKey,
+      payerTokenAccount: PublicKey
+    ) =>
+      program.methods
+        .changePlan()
+        .accountsPartial({
+          collegeAuthority: authority.publicKey,
+          collegeAccount: collegeAccount,
+          metaverfAccount: metaverfAccount,
+          currentPlan: currentPlan,
+          newPlan: newPlan,
+          mintUsdc: mint,
+          acceptedMint: mint.equals(mintUsdc) ? null : acceptedMint,
+          treasury: mintTreasury,
+          payerTokenAccount: payerTokenAccount,
+          tokenProgram: tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+    type College = Awaited<ReturnType<typeof program.account.collegeAccount.fetch>>;
+    // What is left of each payment at `now`, as the program carries it over
+    const carried = (college: College, now: bigint) =>
+      college.payments.map((payment) => ({
+        mint: payment.mint.toBase58(),
+        amount:
+          (BigInt(payment.amount.toString()) * (BigInt(college.paidUntil.toString()) - now)) /
+          (BigInt(college.paidUntil.toString()) - BigInt(college.paidFrom.toString())),
+      }));
+    const payments = (college: College) =>
+      college.payments.map((payment) => ({
+        mint: payment.mint.toBase58(),
+        amount: BigInt(payment.amount.toString()),
+      }));
+
+    // An upgrade pays the price difference for the time left, counted from when it lands
+    let before = await program.account.collegeAccount.fetch(collegeAccount);
+    let balanceBefore = (await getAccount(connection, authorityTokenAccount)).amount;
+    await changePlan(null, plan, mintUsdc, treasury, authorityTokenAccount);
+    let after = await program.account.collegeAccount.fetch(collegeAccount);
+    let now = BigInt(after.paidFrom.toString());
+    const paid = balanceBefore - (await getAccount(connection, authorityTokenAccount)).amount;
+    const left = BigInt(after.paidUntil.toString()) - now;
+    expect(after.plan.toBase58()).to.equal(plan.toBase58());
+    expect(after.paidUntil.toString()).to.equal(before.paidUntil.toString());
+    expect(paid).to.equal(((planFee - defaultFee) * left) / duration);
+    expect(payments(after)).to.deep.equal([
+      { mint: mintUsdc.toBase58(), amount: carried(before, now)[0].amount + paid },
+    ]);
+
+    // A downgrade charges nothing and stretches the time left by the price ratio instead
+    before = after;
+    balanceBefore = (await getAccount(connection, authorityTokenAccount)).amount;
+    await changePlan(plan, null, mintUsdc, treasury, authorityTokenAccount);
+    after = await program.account.collegeAccount.fetch(collegeAccount);
+    now = BigInt(after.paidFrom.toString());
+    expect(after.plan).to.equal(null);
+    expect((await getAccount(connection, authorityTokenAccount)).amount).to.equal(balanceBefore);
+    expect(after.paidUntil.toString()).to.equal(
+      (now + ((BigInt(before.paidUntil.toString()) - now) * planFee) / defaultFee).toString()
+    );
+    expect(payments(after)).to.deep.equal(carried(before, now));
+
+    // Paying an upgrade in another mint keeps what is left of the earlier payment refundable in its own mint
+    before = after;
+    const otherBefore = (await getAccount(connection, otherTokenAccount)).amount;
+    await changePlan(null, plan, otherMint, otherTreasury, otherTokenAccount);
+    after = await program.account.collegeAccount.fetch(collegeAccount);
+    now = BigInt(after.paidFrom.toString());
+    const otherPaid = otherBefore - (await getAccount(connection, otherTokenAccount)).amount;
+    expect(otherPaid).to.equal(
+      ((planFee - defaultFee) * (BigInt(after.paidUntil.toString()) - now)) / duration
+    );
+    const usdcLeft = carried(before, now)[0];
+    expect(usdcLeft.amount > BigInt(0)).to.equal(true);
+    expect(payments(after)).to.deep.equal([usdcLeft, { mint: otherMint.toBase58(), amount: otherPaid }]);
+  });
+
+  it("Mint as an issuer delegate only within its collections, cap, expiry and grantor", async () => {
+    const oldAuthority = Keypair.generate();
+    const newAuthority = Keypair.generate();
+    const staff = Keypair.generate();
+    const shortStaff = Keypair.generate();
+    for (const key of [oldAuthority, newAuthority, staff, shortStaff]) {
+      await fund(key.publicKey, LAMPORTS_PER_SOL);
+    }
+    const { collegeId, collegeAccount, collegeLookup } = await registerCollegeFor(oldAuthority);
+    const [collegeProfile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_profile"), collegeAccount.toBuffer()],
+      program.programId
+    );
+
+    const addCollection = async () => {
+      const collection = Keypair.generate();
+      await program.methods
+        .addCollection(collegeId, { name: "TEST COLLECTION", uri: "https://example.com/event" })
+        .accountsStrict({
+          collegeAccount: collegeAccount,
+          collegeProfile: collegeProfile,
+          collegeAuthority: oldAuthority.publicKey,
+          metaverfAccount: metaverfAccount,
+          plan: null,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          newCollection: collection.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([oldAuthority, collection])
+        .rpc()
+        .then(confirm);
+      return collection.publicKey;
+    };
+    const delegated = await addCollection();
+    const notDelegated = await addCollection();
+
+    const delegateFor = (key: Keypair) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("issuer_delegate"), collegeAccount.toBuffer(), key.publicKey.toBuffer()],
+        program.programId
+      )[0];
+    const createDelegate = (key: Keypair, expiresAt: BN, dailyCap: number) =>
+      program.methods
+        .createIssuerDelegate(collegeId, {
+          delegate: key.publicKey,
+          collections: [delegated],
+          expiresAt: expiresAt,
+          dailyCap: dailyCap,
+        })
+        .accountsPartial({
+          collegeAuthority: oldAuthority.publicKey,
+          collegeAccount: collegeAccount,
+          issuerDelegate: delegateFor(key),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([oldAuthority])
+        .rpc()
+        .then(confirm);
+    const mint = (issuer: Keypair, collection: PublicKey) => {
+      const asset = Keypair.generate();
+      const studentWallet = Keypair.generate();
+      return program.methods
+        .mintCertificate(collegeId, {
+          name: "TEST ASSET",
+          uri: "https://example.com/event",
+          studentName: "STUDENT NAME",
+          courseName: "Turbine",
+          completionDate: "15 feb",
+          grade: "1st year",
+        })
+        .accountsStrict({
+          collegeAccount: collegeAccount,
+          issuer: issuer.publicKey,
+          issuerDelegate: delegateFor(issuer),
+          metaverfAccount: metaverfAccount,
+          plan: null,
+          mintUsdc: null,
+          treasury: null,
+          payerTokenAccount: null,
+          tokenProgram: null,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          collection: collection,
+          systemProgram: SystemProgram.programId,
+          asset: asset.publicKey,
+          studentWallet: studentWallet.publicKey,
+        })
+        .signers([issuer, asset, studentWallet])
+        .rpc();
+    };
+
+    const chainNow = async () => connection.getBlockTime(await connection.getSlot());
+
+    // One certificate a day, into the delegated collection only
+    await createDelegate(staff, new BN((await chainNow()) + 30 * 24 * 60 * 60), 1);
+    await expectError(mint(staff, notDelegated), "CollectionNotDelegated");
+    await mint(staff, delegated).then(confirm);
+    await expectError(mint(staff, delegated), "DailyCapReached");
+    const delegate = await program.account.issuerDelegate.fetch(delegateFor(staff));
+    expect(delegate.mintedToday).to.equal(1);
+    expect(delegate.certificatesIssued.toNumber()).to.equal(1);
+
+    // A delegation stops working once it expires
+    await createDelegate(shortStaff, new BN((await chainNow()) + 3), 0);
+    await sleep(5000);
+    await expectError(mint(shortStaff, delegated), "IssuerDelegateExpired");
+
+    // Delegations granted by a previous authority stop working once the college changes hands
+    await program.methods
+      .proposeCollegeAuthority(collegeId, newAuthority.publicKey)
+      .accountsPartial({
+        collegeAuthority: oldAuthority.publicKey,
+        collegeAccount: collegeAccount,
+      })
+      .signers([oldAuthority])
+      .rpc()
+      .then(confirm);
+    await program.methods
+      .acceptCollegeAuthority(collegeId)
+      .accountsPartial({
+        newAuthority: newAuthority.publicKey,
+        collegeAccount: collegeAccount,
+        oldAuthority: oldAuthority.publicKey,
+        oldLookup: collegeLookup,
+        newLookup: PublicKey.findProgramAddressSync(
+          [Buffer.from("college_lookup"), newAuthority.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([newAuthority])
+      .rpc()
+      .then(confirm);
+    await expectError(mint(staff, delegated), "StaleIssuerDelegate");
+  });
+
+  it("Migrate protocol only as the upgrade authority and only from the baseline", async () => {
+    const [programData] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    );
+    const [pendingParameters] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_parameters")],
+      program.programId
+    );
+    const migrate = (upgradeAuthority: PublicKey) =>
+      program.methods
+        .migrateProtocol()
+        .accountsPartial({
+          upgradeAuthority: upgradeAuthority,
+          metaverfAccount: metaverfAccount,
+          pendingParameters: pendingParameters,
+          mintUsdc: mintUsdc,
+          treasury: treasury,
+          program: program.programId,
+          programData: programData,
+          systemProgram: SystemProgram.programId,
+        });
+
+    await expectError(
+      migrate(collegeAuthority.publicKey).signers([collegeAuthority]).rpc(),
+      "NotUpgradeAuthority"
+    );
+
+    // The provider wallet deployed the program, the protocol it initialized is already current
+    await expectError(migrate(provider.publicKey).rpc(), "AlreadyMigrated");
+
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    expect(protocol.version).to.equal(1);
+  });
+
+  it("Migrate a baseline college off its two-byte seed", async () => {
+    // Loaded from tests/fixtures/legacy_college.json, registered before versioning with ID 7
+    const legacyId = 7;
+    const legacyAuthority = new PublicKey("Hrj4ZfaTERZS1H8kT5CzCUYG3JLhgGiCqDH1YUNuU2Mp");
+    const [legacyCollege] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), new BN(legacyId).toArrayLike(Buffer, "le", 2)],
+      program.programId
+    );
+    const [collegeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), new BN(legacyId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [collegeLookup] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_lookup"), legacyAuthority.toBuffer()],
+      program.programId
+    );
+    const [collegeProfile] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college_profile"), collegeAccount.toBuffer()],
+      program.programId
+    );
+    const migrateCollege = (collegeId: BN, college: PublicKey) =>
+      program.methods
+        .migrateCollege(collegeId)
+        .accountsPartial({
+          payer: provider.publicKey,
+          metaverfAccount: metaverfAccount,
+          collegeAccount: college,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    // `migrate_college` derives the widened address, which is empty until the college is moved
+    await expectError(migrateCollege(new BN(legacyId), collegeAccount), "ConstraintOwner");
+
+    await program.methods
+      .migrateLegacyCollege(legacyId)
+      .accountsPartial({
+        payer: provider.publicKey,
+        metaverfAccount: metaverfAccount,
+        legacyCollege: legacyCollege,
+        collegeAccount: collegeAccount,
+        collegeLookup: collegeLookup,
+        collegeProfile: collegeProfile,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc()
+      .then(confirm);
+
+    const protocol = await program.account.metaverfAccount.fetch(metaverfAccount);
+    const college = await program.account.collegeAccount.fetch(collegeAccount);
+    expect(college.version).to.equal(1);
+    expect(college.legacyId).to.equal(legacyId);
+    expect(college.collegeId.toNumber()).to.equal(legacyId);
+    expect(college.authority.toBase58()).to.equal(legacyAuthority.toBase58());
+    expect(college.active).to.equal(true);
+    expect(college.paidUntil.toString()).to.equal(
+      college.lastPayment.add(protocol.subscriptionDuration).toString()
+    );
+    expect(await connection.getAccountInfo(legacyCollege)).to.equal(null);
+
+    // The lookup and profile baseline colleges never had are created on the way
+    const lookup = await program.account.collegeLookup.fetch(collegeLookup);
+    expect(lookup.collegeId.toNumber()).to.equal(legacyId);
+    expect(lookup.college.toBase58()).to.equal(collegeAccount.toBase58());
+    const profile = await program.account.collegeProfile.fetch(collegeProfile);
+    expect(profile.college.toBase58()).to.equal(collegeAccount.toBase58());
+    expect(profile.name).to.equal("");
+
+    // Colleges at the widened address are on the current layout already
+    await expectError(migrateCollege(new BN(legacyId), collegeAccount), "AlreadyMigrated");
+    const [firstCollege] = PublicKey.findProgramAddressSync(
+      [Buffer.from("college"), firstCollegeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await expectError(migrateCollege(firstCollegeId, firstCollege), "AlreadyMigrated");
+  });
+
+  it("Withdraw Fees last time", async () => {
+    try {
+      const amount = new BN(10); // Withdraw all fees
+      const tx = await program.methods
+        .withdrawFees(amount)
+        .accountsPartial({
+          authority: admin.publicKey,
+          treasurer: null,
+          mintUsdc: mintUsdc,
+          acceptedMint: null,
+          metaverfAccount: metaverfAccount,
+          treasury: treasury,
+          authorityTokenAccount: adminTokenAccount,
+          tokenProgram: tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc()
+        .then(confirm)
+        .then(log);
+
+      console.log("Withdraw success:", tx);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+
+
+
+//TODO PARAMETERS ARE NOT CHANGED ITS AN HECTIC TASK probably will use AI
+
+  // it("Register College", async () => {
+  //   try {
+  //     const collegeId = 1;
+
+  //     // Derive PDA for this specific college ID
+  //     const [collegeAccount] = PublicKey.findProgramAddressSync(
+  //       [Buffer.from("college"), new Uint8Array([collegeId, 0])], // le bytes for u16
+  //       program.programId
+  //     );
+
+  //     const tx = await program.methods
+  //       .registerCollege(collegeId)
+  //       .accountsPartial({
+  //         admin: admin.publicKey,
+  //         mintUsdc: mintUsdc,
+  //         acceptedMint: null,
+  //         collegeAccount: collegeAccount,
+  //         collegeAuthority: collegeAuthority.publicKey,
+  //         metaverfAccount: metaverfAccount,
+  //         treasury: treasury,
+  //         payerTokenAccount: payerTokenAccount,
+  //         tokenProgram: tokenProgram,
+  //         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+  //         systemProgram: SystemProgram.programId,
+  //       })
+  //       .signers([admin, collegeAuthority])
+  //       .rpc({ skipPreflight: true })
+  //       .then(confirm)
+  //       .then(log);
+
+  //     console.log(`Register College signature:`, tx);
+  //   } catch (error) {
+  //     if (error instanceof anchor.web3.SendTransactionError) {
+  //       console.log("Detailed error:", error.logs);
+  //     } else {
+  //       console.log(error);
+  //     }
+  //   }
+  // });
 
   // it("Renew Subscription College", async () => {
   //   try {
@@ -907,10 +3364,11 @@ describe("metaverf", () => {
   //     );
 
   //     const tx = await program.methods
-  //       .renewSubscription(collegeId)
+  //       .renewSubscription(collegeId, 1)
   //       .accountsPartial({
   //         admin: admin.publicKey,
   //         mintUsdc: mintUsdc,
+  //         acceptedMint: null,
   //         collegeAccount: collegeAccount,
   //         collegeAuthority: collegeAuthority.publicKey,
   //         metaverfAccount: metaverfAccount,
@@ -941,11 +3399,12 @@ describe("metaverf", () => {
   // //     const tx = await program.methods
   // //       .withdrawFees(amount)
   // //       .accountsPartial({
-  // //         admin: admin.publicKey,
+  // //         authority: admin.publicKey,
+  // //         treasurer: null,
   // //         mintUsdc: mintUsdc,
   // //         metaverfAccount: metaverfAccount,
   // //         treasury: treasury,
-  // //         adminTokenAccount,
+  // //         authorityTokenAccount: adminTokenAccount,
   // //         tokenProgram: tokenProgram,
   // //         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
   // //         systemProgram: SystemProgram.programId,
@@ -983,6 +3442,7 @@ describe("metaverf", () => {
   //       .accountsStrict({
   //         collegeAccount: collegeAccount,
   //         collegeAuthority: collegeAuthority.publicKey,
+  //         metaverfAccount: metaverfAccount,
   //         mplCoreProgram: MPL_CORE_PROGRAM_ID,
   //         newCollection: collection1.publicKey,
   //         systemProgram: SystemProgram.programId,
@@ -1076,6 +3536,7 @@ describe("metaverf", () => {
   //       .accountsStrict({
   //         collegeAccount: collegeAccount,
   //         collegeAuthority: collegeAuthority.publicKey,
+  //         metaverfAccount: metaverfAccount,
   //         mplCoreProgram: MPL_CORE_PROGRAM_ID,
   //         collection: collection1.publicKey,
   //         systemProgram: SystemProgram.programId,
@@ -1133,6 +3594,7 @@ describe("metaverf", () => {
   //       .accountsStrict({
   //         collegeAccount: collegeAccount,
   //         collegeAuthority: collegeAuthority.publicKey,
+  //         metaverfAccount: metaverfAccount,
   //         mplCoreProgram: MPL_CORE_PROGRAM_ID,
   //         collection: collection1.publicKey,
   //         systemProgram: SystemProgram.programId,
@@ -1191,6 +3653,7 @@ describe("metaverf", () => {
   //       .accountsStrict({
   //         collegeAccount: collegeAccount,
   //         collegeAuthority: collegeAuthority.publicKey,
+  //         metaverfAccount: metaverfAccount,
   //         mplCoreProgram: MPL_CORE_PROGRAM_ID,
   //         collection: collection1.publicKey,
   //         systemProgram: SystemProgram.programId,
@@ -1226,11 +3689,12 @@ describe("metaverf", () => {
   // //     const tx = await program.methods
   // //       .withdrawFees(amount)
   // //       .accountsPartial({
-  // //         admin: admin.publicKey,
+  // //         authority: admin.publicKey,
+  // //         treasurer: null,
   // //         mintUsdc: mintUsdc,
   // //         metaverfAccount: metaverfAccount,
   // //         treasury: treasury,
-  // //         adminTokenAccount,
+  // //         authorityTokenAccount: adminTokenAccount,
   // //         tokenProgram: tokenProgram,
   // //         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
   // //         systemProgram: SystemProgram.programId,
@@ -1424,9 +3888,10 @@ describe("metaverf", () => {
   //     const newAnnualFee = new BN(2e6);
   //     const newSubscriptionDuration = new BN(2e6);
   //     const tx = await program.methods
-  //       .updateParameters(newAnnualFee, newSubscriptionDuration)
+  //       .updateParameters(newAnnualFee, newSubscriptionDuration, null)
   //       .accountsPartial({
-  //         admin: admin.publicKey,
+  //         authority: admin.publicKey,
+  //         feeManager: null,
   //         metaverfAccount: metaverfAccount,
   //       })
   //       .signers([admin])
@@ -1440,17 +3905,78 @@ describe("metaverf", () => {
   //   }
   // });
 
+  it("Distribute fees between the payout recipients", async () => {
+    const partner = Keypair.generate();
+    const partnerTokenAccount = (await getOrCreateAssociatedTokenAccount(
+      connection,
+      admin,
+      mintUsdc,
+      partner.publicKey,
+      false
+    )).address;
+
+    const setRecipients = (partnerShareBps: number) =>
+      program.methods
+        .setPayoutRecipients([
+          { owner: admin.publicKey, shareBps: 6000 },
+          { owner: partner.publicKey, shareBps: partnerShareBps },
+        ])
+        .accountsPartial({
+          admin: admin.publicKey,
+          metaverfAccount: metaverfAccount,
+        })
+        .signers([admin])
+        .rpc();
+
+    const distribute = (recipientAccounts: PublicKey[]) =>
+      program.methods
+        .distributeFees()
+        .accountsPartial({
+          mintUsdc: mintUsdc,
+          acceptedMint: null,
+          metaverfAccount: metaverfAccount,
+          treasury: treasury,
+          tokenProgram: tokenProgram,
+        })
+        .remainingAccounts(
+          recipientAccounts.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }))
+        )
+        .rpc();
+
+    await expectError(distribute([adminTokenAccount]), "PayoutNotConfigured");
+
+    // Shares must add up to 10_000 bps
+    await expectError(setRecipients(3000), "InvalidPayoutConfig");
+    await setRecipients(4000).then(confirm);
+
+    // Token accounts must follow the configured order
+    await expectError(distribute([partnerTokenAccount, adminTokenAccount]), "InvalidPayoutAccount");
+    await expectError(distribute([adminTokenAccount]), "InvalidPayoutAccount");
+
+    const balance = (await getAccount(connection, treasury)).amount;
+    const adminBefore = (await getAccount(connection, adminTokenAccount)).amount;
+
+    await distribute([adminTokenAccount, partnerTokenAccount]).then(confirm);
+
+    const adminAfter = (await getAccount(connection, adminTokenAccount)).amount;
+    const partnerAfter = (await getAccount(connection, partnerTokenAccount)).amount;
+    expect(adminAfter - adminBefore).to.equal((balance * BigInt(6000)) / BigInt(10000));
+    expect(partnerAfter).to.equal((balance * BigInt(4000)) / BigInt(10000));
+  });
+
   // it("Withdraw Fees last time", async () => {
   //   try {
   //     const amount = new BN(10); // Withdraw all fees
   //     const tx = await program.methods
   //       .withdrawFees(amount)
   //       .accountsPartial({
-  //         admin: admin.publicKey,
+  //         authority: admin.publicKey,
+  //         treasurer: null,
   //         mintUsdc: mintUsdc,
+  //         acceptedMint: null,
   //         metaverfAccount: metaverfAccount,
   //         treasury: treasury,
-  //         adminTokenAccount,
+  //         authorityTokenAccount: adminTokenAccount,
   //         tokenProgram: tokenProgram,
   //         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
   //         systemProgram: SystemProgram.programId,